@@ -169,7 +169,7 @@ pub fn genesis_config() -> ChainSpec {
 					now: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis(),
 					elapsed_time: 0,
 					collator_count: 3,
-					collators: vec![(array_bytes::hex_n_into_unchecked(ALITH), UNIT)],
+					collators: vec![(array_bytes::hex_n_into_unchecked(ALITH), 1_000 * UNIT)],
 				},
 				session: SessionConfig {
 					keys: vec![(
@@ -273,7 +273,7 @@ fn testnet_genesis(
 			now: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis(),
 			elapsed_time: 0,
 			collator_count: collators.len() as _,
-			collators: collators.iter().map(|(a, _)| (a.to_owned(), UNIT)).collect(),
+			collators: collators.iter().map(|(a, _)| (a.to_owned(), 1_000 * UNIT)).collect(),
 		},
 		session: SessionConfig {
 			keys: collators
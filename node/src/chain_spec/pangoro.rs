@@ -156,13 +156,24 @@ pub fn genesis_config() -> ChainSpec {
 				},
 				transaction_payment: Default::default(),
 				assets: AssetsConfig {
-					assets: vec![(AssetIds::OKton as _, ROOT, true, 1)],
-					metadata: vec![(
-						AssetIds::OKton as _,
-						b"Pangoro Commitment Token".to_vec(),
-						b"OKTON".to_vec(),
-						18,
-					)],
+					assets: vec![
+						(AssetIds::OKton as _, ROOT, true, 1),
+						(AssetIds::LORing as _, ROOT, true, 1),
+					],
+					metadata: vec![
+						(
+							AssetIds::OKton as _,
+							b"Pangoro Commitment Token".to_vec(),
+							b"OKTON".to_vec(),
+							18,
+						),
+						(
+							AssetIds::LORing as _,
+							b"Pangoro Liquid Staking Token".to_vec(),
+							b"LORING".to_vec(),
+							18,
+						),
+					],
 					..Default::default()
 				},
 				vesting: Default::default(),
@@ -173,9 +184,9 @@ pub fn genesis_config() -> ChainSpec {
 					elapsed_time: 11_516_352_020,
 					collator_count: 3,
 					collators: vec![
-						(array_bytes::hex_n_into_unchecked(C1), UNIT),
-						(array_bytes::hex_n_into_unchecked(C2), UNIT),
-						(array_bytes::hex_n_into_unchecked(C3), UNIT),
+						(array_bytes::hex_n_into_unchecked(C1), 1_000 * UNIT),
+						(array_bytes::hex_n_into_unchecked(C2), 1_000 * UNIT),
+						(array_bytes::hex_n_into_unchecked(C3), 1_000 * UNIT),
 					],
 				},
 				session: SessionConfig {
@@ -282,13 +293,19 @@ fn testnet_genesis(
 		},
 		transaction_payment: Default::default(),
 		assets: AssetsConfig {
-			assets: vec![(AssetIds::OKton as _, ROOT, true, 1)],
-			metadata: vec![(
-				AssetIds::OKton as _,
-				b"Pangoro Commitment Token".to_vec(),
-				b"OKTON".to_vec(),
-				18,
-			)],
+			assets: vec![
+				(AssetIds::OKton as _, ROOT, true, 1),
+				(AssetIds::LORing as _, ROOT, true, 1),
+			],
+			metadata: vec![
+				(AssetIds::OKton as _, b"Pangoro Commitment Token".to_vec(), b"OKTON".to_vec(), 18),
+				(
+					AssetIds::LORing as _,
+					b"Pangoro Liquid Staking Token".to_vec(),
+					b"LORING".to_vec(),
+					18,
+				),
+			],
 			..Default::default()
 		},
 		vesting: Default::default(),
@@ -298,7 +315,7 @@ fn testnet_genesis(
 			now: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis(),
 			elapsed_time: 0,
 			collator_count: collators.len() as _,
-			collators: collators.iter().map(|(a, _)| (a.to_owned(), UNIT)).collect(),
+			collators: collators.iter().map(|(a, _)| (a.to_owned(), 1_000 * UNIT)).collect(),
 		},
 		session: SessionConfig {
 			keys: collators
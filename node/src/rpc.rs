@@ -21,13 +21,24 @@
 //! used by Substrate nodes. This file extends those RPC definitions with
 //! capabilities that are specific to this project's runtime configuration.
 
+pub mod deposit;
+pub mod staking;
+
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 
 // std
 use std::sync::Arc;
+// crates.io
+use jsonrpsee::{
+	core::Error as JsonRpseeError,
+	types::error::{CallError, ErrorObject},
+};
 // darwinia
 use dc_primitives::*;
 
+/// The error code of the runtime API call failure.
+const RUNTIME_ERROR: i32 = 1;
+
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
@@ -82,10 +93,15 @@ where
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ sp_block_builder::BlockBuilder<Block>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
-		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+		+ darwinia_staking::runtime_api::StakingApi<Block, AccountId, BlockNumber>
+		+ darwinia_deposit::runtime_api::DepositApi<Block, AccountId>,
 	P: 'static + Sync + Send + sc_transaction_pool_api::TransactionPool<Block = Block>,
 	A: 'static + sc_transaction_pool::ChainApi<Block = Block>,
 {
+	// darwinia
+	use deposit::{Deposit, DepositApiServer};
+	use staking::{Staking, StakingApiServer};
 	// frontier
 	use fc_rpc::{
 		Eth, EthApiServer, EthFilter, EthFilterApiServer, EthPubSub, EthPubSubApiServer, Net,
@@ -115,6 +131,8 @@ where
 
 	module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Staking::new(client.clone()).into_rpc())?;
+	module.merge(Deposit::new(client.clone()).into_rpc())?;
 	module.merge(
 		Eth::new(
 			client.clone(),
@@ -171,3 +189,12 @@ where
 
 	Ok(module)
 }
+
+fn runtime_error_into_rpc_err(e: impl std::fmt::Debug) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Runtime API call failed",
+		Some(format!("{e:?}")),
+	))
+	.into()
+}
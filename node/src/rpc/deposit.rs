@@ -0,0 +1,104 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Deposit RPC methods.
+//!
+//! Serve the [`darwinia_deposit::runtime_api::DepositApi`] under the `deposit_*` namespace.
+
+// std
+use std::sync::Arc;
+// crates.io
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+// darwinia
+use super::runtime_error_into_rpc_err;
+use darwinia_deposit::{runtime_api::DepositApi as DepositRuntimeApi, DepositQuote};
+use dc_primitives::*;
+// substrate
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::generic::BlockId;
+
+/// Deposit RPC methods.
+#[rpc(server)]
+pub trait DepositApi {
+	/// Quote the deposits of the given account.
+	#[method(name = "deposit_depositsOf")]
+	fn deposits_of(&self, who: AccountId, at: Option<Hash>) -> RpcResult<Vec<DepositQuote>>;
+
+	/// Quote the KTON interest of locking `amount` RING for `months`.
+	#[method(name = "deposit_quoteLock")]
+	fn quote_lock(
+		&self,
+		amount: NumberOrHex,
+		months: u8,
+		at: Option<Hash>,
+	) -> RpcResult<NumberOrHex>;
+}
+
+/// Deposit RPC methods implementation.
+pub struct Deposit<C> {
+	client: Arc<C>,
+}
+impl<C> Deposit<C> {
+	/// Create a new instance.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+
+	fn at(&self, at: Option<Hash>) -> BlockId<Block>
+	where
+		C: HeaderBackend<Block>,
+	{
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+impl<C> DepositApiServer for Deposit<C>
+where
+	C: 'static + Send + Sync + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DepositRuntimeApi<Block, AccountId>,
+{
+	fn deposits_of(&self, who: AccountId, at: Option<Hash>) -> RpcResult<Vec<DepositQuote>> {
+		self.client.runtime_api().deposits_of(&self.at(at), who).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn quote_lock(
+		&self,
+		amount: NumberOrHex,
+		months: u8,
+		at: Option<Hash>,
+	) -> RpcResult<NumberOrHex> {
+		let amount = amount.try_into().map_err(|_| {
+			CallError::Custom(ErrorObject::owned(
+				ErrorCode::InvalidParams.code(),
+				"Amount doesn't fit in a balance",
+				None::<()>,
+			))
+		})?;
+
+		self.client
+			.runtime_api()
+			.quote_lock(&self.at(at), amount, months)
+			.map(Into::into)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
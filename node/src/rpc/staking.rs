@@ -0,0 +1,199 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Staking RPC methods.
+//!
+//! Serve the [`darwinia_staking::runtime_api::StakingApi`] under the `staking_*` namespace.
+
+// std
+use std::sync::Arc;
+// crates.io
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+// darwinia
+use super::runtime_error_into_rpc_err;
+use darwinia_staking::{
+	runtime_api::StakingApi as StakingRuntimeApi, ExposureSummary, LedgerSummary, Power,
+	RewardPoint, SessionSummary,
+};
+use dc_primitives::*;
+// substrate
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::generic::BlockId;
+
+/// Staking RPC methods.
+#[rpc(server)]
+pub trait StakingApi {
+	/// Calculate the power of the given account.
+	#[method(name = "staking_powerOf")]
+	fn power_of(&self, who: AccountId, at: Option<Hash>) -> RpcResult<Power>;
+
+	/// Summarize the ledger of the given account.
+	#[method(name = "staking_ledgerSummaryOf")]
+	fn ledger_summary_of(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<LedgerSummary<BlockNumber>>>;
+
+	/// Get the exposure of the given collator in the active session.
+	#[method(name = "staking_exposureOf")]
+	fn exposure_of(
+		&self,
+		collator: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<ExposureSummary<AccountId>>>;
+
+	/// Estimate the payout of the given collator for the active session, nominators' part
+	/// included.
+	#[method(name = "staking_estimatedSessionPayoutOf")]
+	fn estimated_session_payout_of(
+		&self,
+		collator: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<NumberOrHex>;
+
+	/// Rank the collator candidates by their total power, in descending order.
+	#[method(name = "staking_collatorRanking")]
+	fn collator_ranking(&self, at: Option<Hash>) -> RpcResult<Vec<(AccountId, Power)>>;
+
+	/// Get the archived exposure of the given collator at the given session.
+	#[method(name = "staking_historicalExposureOf")]
+	fn historical_exposure_of(
+		&self,
+		session: u32,
+		collator: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<ExposureSummary<AccountId>>>;
+
+	/// Get the archived reward points of the collators at the given session.
+	#[method(name = "staking_historicalRewardPoints")]
+	fn historical_reward_points(
+		&self,
+		session: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(AccountId, RewardPoint)>>;
+
+	/// Get the archived payout figures of the given session.
+	#[method(name = "staking_sessionSummaryOf")]
+	fn session_summary_of(
+		&self,
+		session: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Option<SessionSummary>>;
+}
+
+/// Staking RPC methods implementation.
+pub struct Staking<C> {
+	client: Arc<C>,
+}
+impl<C> Staking<C> {
+	/// Create a new instance.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+
+	fn at(&self, at: Option<Hash>) -> BlockId<Block>
+	where
+		C: HeaderBackend<Block>,
+	{
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+impl<C> StakingApiServer for Staking<C>
+where
+	C: 'static + Send + Sync + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: StakingRuntimeApi<Block, AccountId, BlockNumber>,
+{
+	fn power_of(&self, who: AccountId, at: Option<Hash>) -> RpcResult<Power> {
+		self.client.runtime_api().power_of(&self.at(at), who).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn ledger_summary_of(
+		&self,
+		who: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<LedgerSummary<BlockNumber>>> {
+		self.client
+			.runtime_api()
+			.ledger_summary_of(&self.at(at), who)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn exposure_of(
+		&self,
+		collator: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<ExposureSummary<AccountId>>> {
+		self.client
+			.runtime_api()
+			.exposure_of(&self.at(at), collator)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn estimated_session_payout_of(
+		&self,
+		collator: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<NumberOrHex> {
+		self.client
+			.runtime_api()
+			.estimated_session_payout_of(&self.at(at), collator)
+			.map(Into::into)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn collator_ranking(&self, at: Option<Hash>) -> RpcResult<Vec<(AccountId, Power)>> {
+		self.client.runtime_api().collator_ranking(&self.at(at)).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn historical_exposure_of(
+		&self,
+		session: u32,
+		collator: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Option<ExposureSummary<AccountId>>> {
+		self.client
+			.runtime_api()
+			.historical_exposure_of(&self.at(at), session, collator)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn historical_reward_points(
+		&self,
+		session: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<(AccountId, RewardPoint)>> {
+		self.client
+			.runtime_api()
+			.historical_reward_points(&self.at(at), session)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn session_summary_of(
+		&self,
+		session: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Option<SessionSummary>> {
+		self.client
+			.runtime_api()
+			.session_summary_of(&self.at(at), session)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
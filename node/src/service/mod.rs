@@ -111,6 +111,8 @@ pub trait RuntimeApiCollection:
 	+ fp_rpc::EthereumRuntimeRPCApi<Block>
 	+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 	+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+	+ darwinia_staking::runtime_api::StakingApi<Block, AccountId, BlockNumber>
+	+ darwinia_deposit::runtime_api::DepositApi<Block, AccountId>
 {
 }
 impl<Api> RuntimeApiCollection for Api where
@@ -126,6 +128,8 @@ impl<Api> RuntimeApiCollection for Api where
 		+ fp_rpc::EthereumRuntimeRPCApi<Block>
 		+ fp_rpc::ConvertTransactionRuntimeApi<Block>
 		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+		+ darwinia_staking::runtime_api::StakingApi<Block, AccountId, BlockNumber>
+		+ darwinia_deposit::runtime_api::DepositApi<Block, AccountId>
 {
 }
 
@@ -45,7 +45,7 @@
 
 // darwinia
 use darwinia_deposit::Deposit;
-use darwinia_staking::Ledger;
+use darwinia_staking::LegacyLedger;
 use dc_primitives::{AccountId as AccountId20, AssetId, Balance, BlockNumber, Index};
 // substrate
 use frame_support::{
@@ -167,8 +167,9 @@ pub mod pallet {
 
 	/// [`darwinia_staking::Ledgers`] data.
 	#[pallet::storage]
+	#[pallet::unbounded]
 	#[pallet::getter(fn ledger_of)]
-	pub type Ledgers<T: Config> = StorageMap<_, Blake2_128Concat, AccountId32, Ledger<T>>;
+	pub type Ledgers<T: Config> = StorageMap<_, Blake2_128Concat, AccountId32, LegacyLedger<T>>;
 
 	/// Multisig migration caches.
 	#[pallet::storage]
@@ -466,6 +467,8 @@ pub mod pallet {
 					}
 				}
 
+				let l = <darwinia_staking::Pallet<T>>::split_legacy_ledger(&to, l);
+
 				<darwinia_staking::Ledgers<T>>::insert(to, l);
 			}
 
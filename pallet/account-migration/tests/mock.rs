@@ -45,6 +45,15 @@ impl darwinia_staking::Stake for Dummy {
 		Ok(())
 	}
 }
+impl darwinia_staking::Slash for Dummy {
+	fn slash(
+		_: &Self::AccountId,
+		_: Self::Item,
+		_: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		Ok(0)
+	}
+}
 impl frame_support::traits::UnixTime for Dummy {
 	fn now() -> core::time::Duration {
 		core::time::Duration::new(0, 0)
@@ -151,26 +160,49 @@ impl pallet_vesting::Config for Runtime {
 }
 
 impl darwinia_deposit::Config for Runtime {
+	type DepositInterest = ();
 	type Kton = Dummy;
+	type LinearVesting = ();
 	type MaxDeposits = ();
+	type MaxLockingMonths = ();
 	type MinLockingAmount = ();
+	type MinLockingMonths = ();
+	type PenaltyMultiplier = ();
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type Staking = Staking;
 	type WeightInfo = ();
 }
 
 impl darwinia_staking::Config for Runtime {
+	type CommissionChangeDelay = ();
 	type Deposit = Deposit;
+	type HistoryDepth = ();
+	type InactivitySlashFraction = ();
+	type KeysRegistry = frame_support::traits::Everything;
 	type Kton = Dummy;
+	type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxCommission = ();
+	type MaxCommissionDelta = ();
 	type MaxDeposits = ();
-	type MaxUnstakings = ();
+	type MaxInactiveSessions = ConstU32<2>;
+	type MaxNominations = ();
+	type MaxNominatorsPerCollator = ();
+	type MaxSlashFraction = ();
+	type MinAuthoredBlocks = ConstU32<0>;
+	type MinCollatorStake = ();
 	type MinStakingDuration = ();
+	type NextSessionRotation = ();
 	type PayoutFraction = ();
+	type PowerCalculator = ();
 	type RewardRemainder = ();
 	type Ring = Dummy;
 	type RingCurrency = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type SlashCancelOrigin = frame_system::EnsureRoot<AccountId>;
+	type SlashDeferDuration = ();
 	type UnixTime = Dummy;
+	type WeightInfo = ();
 }
 
 impl pallet_identity::Config for Runtime {
@@ -41,12 +41,12 @@ mod benchmarks {
 		//
 		// Calculate the last deposit's id.
 		(0..max_deposits - 1).for_each(|_| {
-			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, MAX_LOCKING_MONTHS)
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
 				.unwrap()
 		});
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(a), UNIT, MAX_LOCKING_MONTHS);
+		_(RawOrigin::Signed(a), UNIT, T::MaxLockingMonths::get());
 	}
 
 	#[benchmark]
@@ -57,7 +57,7 @@ mod benchmarks {
 		T::Ring::make_free_balance_be(&a, max_deposits as Balance * UNIT);
 
 		(0..max_deposits).for_each(|_| {
-			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, MAX_LOCKING_MONTHS)
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
 				.unwrap()
 		});
 
@@ -66,7 +66,7 @@ mod benchmarks {
 		// Let all locks be expired.
 		<pallet_timestamp::Pallet<T>>::set_timestamp(
 			<pallet_timestamp::Pallet<T>>::now()
-				+ (MAX_LOCKING_MONTHS as Moment * MILLISECS_PER_MONTH).saturated_into(),
+				+ (T::MaxLockingMonths::get() as Moment * MILLISECS_PER_MONTH).saturated_into(),
 		);
 
 		assert_eq!(<Pallet<T>>::deposit_of(&a).unwrap().len(), max_deposits as usize);
@@ -86,7 +86,7 @@ mod benchmarks {
 		T::Kton::mint(&a, UNIT).unwrap();
 
 		(0..max_deposits).for_each(|_| {
-			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, MAX_LOCKING_MONTHS)
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
 				.unwrap()
 		});
 
@@ -111,6 +111,235 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn transfer_deposit() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let b = frame_benchmarking::account::<T::AccountId>("receiver", 0, 0);
+		let max_deposits = T::MaxDeposits::get();
+
+		T::Ring::make_free_balance_be(&a, 2 * UNIT);
+		T::Ring::make_free_balance_be(&b, max_deposits as Balance * UNIT);
+
+		<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
+			.unwrap();
+
+		// The worst case:
+		//
+		// Remove the sender's last deposit and calculate the receiver's last deposit's id.
+		(0..max_deposits - 1).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(b.clone()).into(), UNIT, T::MaxLockingMonths::get())
+				.unwrap()
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), 0, b.clone());
+
+		assert!(<Pallet<T>>::deposit_of(&a).is_none());
+		assert_eq!(<Pallet<T>>::deposit_of(&b).unwrap().len(), max_deposits as usize);
+	}
+
+	#[benchmark]
+	fn extend() {
+		let a = frame_benchmarking::whitelisted_caller();
+		let max_deposits = T::MaxDeposits::get();
+
+		T::Ring::make_free_balance_be(&a, max_deposits as Balance * UNIT);
+
+		// The worst case:
+		//
+		// Extend the last deposit of a 'full-size' bounded vector, which is in use.
+		(0..max_deposits).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MinLockingMonths::get())
+				.unwrap()
+		});
+		<Deposits<T>>::mutate(&a, |ds| ds.as_mut().unwrap().last_mut().unwrap().in_use = true);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(a.clone()),
+			max_deposits as DepositId - 1,
+			T::MaxLockingMonths::get() - T::MinLockingMonths::get(),
+		);
+
+		assert_eq!(
+			<Pallet<T>>::deposit_of(&a).unwrap().last().unwrap().expired_time,
+			T::MaxLockingMonths::get() as Moment * MILLISECS_PER_MONTH
+				+ <pallet_timestamp::Pallet<T>>::now().saturated_into::<Moment>()
+		);
+	}
+
+	#[benchmark]
+	fn relock() {
+		let a = frame_benchmarking::whitelisted_caller();
+		let max_deposits = T::MaxDeposits::get();
+
+		T::Ring::make_free_balance_be(&a, max_deposits as Balance * UNIT);
+
+		(0..max_deposits).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MinLockingMonths::get())
+				.unwrap()
+		});
+
+		// The worst case:
+		//
+		// Relock the last deposit of a 'full-size' bounded vector, which is in use.
+		<Deposits<T>>::mutate(&a, |ds| ds.as_mut().unwrap().last_mut().unwrap().in_use = true);
+		<pallet_timestamp::Pallet<T>>::set_timestamp(
+			<pallet_timestamp::Pallet<T>>::now()
+				+ (T::MinLockingMonths::get() as Moment * MILLISECS_PER_MONTH).saturated_into(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), max_deposits as DepositId - 1, T::MaxLockingMonths::get());
+	}
+
+	#[benchmark]
+	fn split_deposit() {
+		let a = frame_benchmarking::whitelisted_caller();
+		let max_deposits = T::MaxDeposits::get();
+
+		T::Ring::make_free_balance_be(&a, max_deposits as Balance * UNIT);
+
+		// The worst case:
+		//
+		// Split the first deposit and calculate the last deposit's id.
+		<Pallet<T>>::lock(
+			RawOrigin::Signed(a.clone()).into(),
+			2 * UNIT,
+			T::MaxLockingMonths::get(),
+		)
+		.unwrap();
+		(0..max_deposits - 2).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
+				.unwrap()
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), 0, UNIT);
+
+		assert_eq!(<Pallet<T>>::deposit_of(&a).unwrap().len(), max_deposits as usize);
+	}
+
+	#[benchmark]
+	fn partially_claim_with_penalty() {
+		let a = frame_benchmarking::whitelisted_caller();
+		let max_deposits = T::MaxDeposits::get();
+
+		T::Ring::make_free_balance_be(&a, (max_deposits + 1) as Balance * UNIT);
+		T::Kton::mint(&a, UNIT).unwrap();
+
+		// The worst case:
+		//
+		// Claim from the last deposit of a 'full-size' bounded vector.
+		(0..max_deposits - 1).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
+				.unwrap()
+		});
+		<Pallet<T>>::lock(
+			RawOrigin::Signed(a.clone()).into(),
+			2 * UNIT,
+			T::MaxLockingMonths::get(),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), max_deposits as DepositId - 1, UNIT);
+
+		assert_eq!(<Pallet<T>>::deposit_of(&a).unwrap().last().unwrap().value, UNIT);
+	}
+
+	#[benchmark]
+	fn claim_ids(x: Linear<1, { T::MaxDeposits::get() }>) {
+		let a = frame_benchmarking::whitelisted_caller();
+		let max_deposits = T::MaxDeposits::get();
+
+		T::Ring::make_free_balance_be(&a, max_deposits as Balance * UNIT);
+
+		(0..max_deposits).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
+				.unwrap()
+		});
+
+		// The worst case:
+		//
+		// Claim from the tail of a 'full-size' bounded vector.
+		<pallet_timestamp::Pallet<T>>::set_timestamp(
+			<pallet_timestamp::Pallet<T>>::now()
+				+ (T::MaxLockingMonths::get() as Moment * MILLISECS_PER_MONTH).saturated_into(),
+		);
+
+		let ids = (max_deposits - x..max_deposits).rev().map(|i| i as DepositId).collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), ids);
+
+		assert_eq!(
+			<Pallet<T>>::deposit_of(&a).map(|ds| ds.len()).unwrap_or_default(),
+			(max_deposits - x) as usize
+		);
+	}
+
+	#[benchmark]
+	fn set_auto_claim() {
+		let a = frame_benchmarking::whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), true);
+
+		assert!(<AutoClaims<T>>::contains_key(&a));
+	}
+
+	#[benchmark]
+	fn auto_claim(x: Linear<0, { T::MaxDeposits::get() }>) {
+		let a = frame_benchmarking::whitelisted_caller();
+
+		T::Ring::make_free_balance_be(&a, x as Balance * UNIT);
+
+		(0..x).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
+				.unwrap()
+		});
+		<Pallet<T>>::set_auto_claim(RawOrigin::Signed(a.clone()).into(), true).unwrap();
+
+		// The worst case:
+		//
+		// Claim all the `x` deposits, and remove the account from the `AutoClaims`.
+		<pallet_timestamp::Pallet<T>>::set_timestamp(
+			<pallet_timestamp::Pallet<T>>::now()
+				+ (T::MaxLockingMonths::get() as Moment * MILLISECS_PER_MONTH).saturated_into(),
+		);
+
+		#[block]
+		{
+			<Pallet<T>>::auto_claim(Weight::MAX);
+		}
+
+		assert!(<Pallet<T>>::deposit_of(&a).is_none());
+		assert!(!<AutoClaims<T>>::contains_key(&a));
+	}
+
+	#[benchmark]
+	fn claim_vested_kton() {
+		let a = frame_benchmarking::whitelisted_caller();
+		let max_deposits = T::MaxDeposits::get();
+
+		T::Ring::make_free_balance_be(&a, max_deposits as Balance * UNIT);
+
+		// The worst case:
+		//
+		// Claim the vested interest of a 'full-size' bounded vector.
+		(0..max_deposits).for_each(|_| {
+			<Pallet<T>>::lock(RawOrigin::Signed(a.clone()).into(), UNIT, T::MaxLockingMonths::get())
+				.unwrap()
+		});
+		<pallet_timestamp::Pallet<T>>::set_timestamp(
+			<pallet_timestamp::Pallet<T>>::now() + MILLISECS_PER_MONTH.saturated_into(),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a));
+	}
+
 	frame_benchmarking::impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -26,6 +26,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
+pub mod runtime_api;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -44,6 +46,8 @@ use core::{
 };
 // crates.io
 use codec::FullCodec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 // darwinia
 use dc_inflation::MILLISECS_PER_YEAR;
 use dc_types::{Balance, Moment};
@@ -58,12 +62,15 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::{traits::AccountIdConversion, Perbill, Perquintill};
+use sp_std::prelude::*;
 
 /// Milliseconds per month.
 pub const MILLISECS_PER_MONTH: Moment = MILLISECS_PER_YEAR / 12;
 
-/// The maximum locking period for a deposit.
+/// The maximum locking period of the GringottsBank.
+///
+/// The legacy deposits follow it, use [`Config::MaxLockingMonths`] for the new ones.
 pub const MAX_LOCKING_MONTHS: u8 = 36;
 
 /// Simple asset APIs.
@@ -78,6 +85,18 @@ pub trait SimpleAsset {
 	fn burn(who: &Self::AccountId, amount: Balance) -> DispatchResult;
 }
 
+/// Deposit interest curve.
+pub trait DepositInterest {
+	/// Calculate the KTON interest of locking `amount` RING for `months`.
+	fn interest(amount: Balance, months: u8) -> Balance;
+}
+/// The GringottsBank-compatible curve, see [`dc_inflation::deposit_interest`].
+impl DepositInterest for () {
+	fn interest(amount: Balance, months: u8) -> Balance {
+		dc_inflation::deposit_interest(amount, months)
+	}
+}
+
 /// Deposit identifier.
 ///
 /// It's not a global-unique identifier.
@@ -99,6 +118,95 @@ pub struct Deposit {
 	pub expired_time: Moment,
 	/// Deposit state.
 	pub in_use: bool,
+	/// The KTON interest of the whole locking period.
+	pub kton_reward: Balance,
+	/// The KTON interest, which has been paid to the owner.
+	///
+	/// It's equal to the [`Deposit::kton_reward`] if the interest was paid upfront.
+	pub kton_claimed: Balance,
+}
+
+/// The deposit layout which didn't track the KTON interest.
+///
+/// Only used by the migrations, convert it with [`Into<Deposit>`].
+#[derive(PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct LegacyDeposit {
+	/// Deposit ID.
+	pub id: DepositId,
+	/// Deposited RING.
+	pub value: Balance,
+	/// Start timestamp.
+	pub start_time: Moment,
+	/// Expired timestamp.
+	pub expired_time: Moment,
+	/// Deposit state.
+	pub in_use: bool,
+}
+impl From<LegacyDeposit> for Deposit {
+	fn from(legacy: LegacyDeposit) -> Self {
+		let LegacyDeposit { id, value, start_time, expired_time, in_use } = legacy;
+		let promise_m = (expired_time.saturating_sub(start_time) / MILLISECS_PER_MONTH)
+			.min(MAX_LOCKING_MONTHS as _);
+		// The interest of a legacy deposit was paid upfront.
+		let kton_reward = dc_inflation::deposit_interest(value, promise_m as _);
+
+		Self { id, value, start_time, expired_time, in_use, kton_reward, kton_claimed: kton_reward }
+	}
+}
+
+// Serialize the amounts as strings, the JSON clients lose precision beyond 2^53.
+#[cfg(feature = "std")]
+mod serde_balance {
+	// crates.io
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+	// darwinia
+	use dc_types::Balance;
+
+	pub fn serialize<S>(balance: &Balance, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_str(&balance.to_string())
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Balance, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+	}
+}
+
+/// A deposit with its computed figures at a specific time.
+///
+/// The amounts are serialized as strings.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DepositQuote {
+	/// Deposit ID.
+	pub id: DepositId,
+	/// Deposited RING.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub value: Balance,
+	/// Start timestamp.
+	pub start_time: Moment,
+	/// Expired timestamp.
+	pub expired_time: Moment,
+	/// Number of months until the deposit expires, rounded up.
+	pub remaining_months: u8,
+	/// The KTON penalty of claiming the deposit now.
+	///
+	/// Zero if the deposit is already expired.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub kton_penalty: Balance,
+	/// The vested KTON interest, which could be claimed through the [`Call::claim_vested_kton`]
+	/// now.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub claimable_kton: Balance,
+	/// Whether the deposit could be claimed through the [`Call::claim`] now.
+	pub claimable: bool,
+	/// Whether the deposit is in use by staking.
+	pub in_use: bool,
 }
 
 #[frame_support::pallet]
@@ -120,15 +228,48 @@ pub mod pallet {
 		/// KTON asset.
 		type Kton: SimpleAsset<AccountId = Self::AccountId>;
 
+		/// The KTON interest curve.
+		type DepositInterest: DepositInterest;
+
+		/// The staking pallet, which refreshes the backings after an in use deposit has been
+		/// extended or relocked.
+		type Staking: darwinia_staking::RefreshBackings<Self::AccountId>;
+
 		/// Minimum amount to lock at least.
 		#[pallet::constant]
 		type MinLockingAmount: Get<Balance>;
 
+		/// Minimum locking period, in months.
+		///
+		/// A deposit is always locked for one month at least.
+		#[pallet::constant]
+		type MinLockingMonths: Get<u8>;
+
+		/// Maximum locking period, in months.
+		///
+		/// It must not exceed the [`MAX_LOCKING_MONTHS`], the interest calculation overflows beyond
+		/// it.
+		#[pallet::constant]
+		type MaxLockingMonths: Get<u8>;
+
+		/// The multiple of the unvested interest, which is charged for claiming an unexpired
+		/// deposit.
+		///
+		/// Not used under the [`Config::LinearVesting`] mode.
+		#[pallet::constant]
+		type PenaltyMultiplier: Get<u32>;
+
 		/// Maximum deposit count.
 		///
 		/// In currently design, this should not be greater than `u8::MAX`.
 		#[pallet::constant]
 		type MaxDeposits: Get<u32>;
+
+		/// Vest the KTON interest linearly over the locking period, instead of paying it upfront.
+		///
+		/// Under this mode, claiming an unexpired deposit only forfeits the unvested interest.
+		#[pallet::constant]
+		type LinearVesting: Get<bool>;
 	}
 
 	#[allow(missing_docs)]
@@ -152,16 +293,56 @@ pub mod pallet {
 			deposit_id: DepositId,
 			kton_penalty: Balance,
 		},
+		/// A deposit has been transferred to another account with a new id.
+		DepositTransferred {
+			from: T::AccountId,
+			to: T::AccountId,
+			deposit_id: DepositId,
+			new_deposit_id: DepositId,
+		},
+		/// An unexpired deposit's locking period has been extended.
+		DepositExtended {
+			owner: T::AccountId,
+			deposit_id: DepositId,
+			expired_time: Moment,
+			kton_reward: Balance,
+		},
+		/// An expired deposit has been locked again.
+		DepositRelocked {
+			owner: T::AccountId,
+			deposit_id: DepositId,
+			start_time: Moment,
+			expired_time: Moment,
+			kton_reward: Balance,
+		},
+		/// A part of a deposit has been split into a new deposit.
+		DepositSplit {
+			owner: T::AccountId,
+			deposit_id: DepositId,
+			new_deposit_id: DepositId,
+			value: Balance,
+		},
+		/// A part of an unexpired deposit has been claimed by paying the KTON penalty.
+		DepositPartiallyClaimedWithPenalty {
+			owner: T::AccountId,
+			deposit_id: DepositId,
+			value: Balance,
+			kton_penalty: Balance,
+		},
+		/// The automatic claiming of an account has been switched.
+		AutoClaimSet { owner: T::AccountId, enabled: bool },
+		/// The vested KTON interest has been claimed.
+		VestedKtonClaimed { owner: T::AccountId, amount: Balance },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Lock at least for a specific amount.
 		LockAtLeastSome,
-		/// Lock at least for one month.
-		LockAtLeastOneMonth,
-		/// Lock at most for thirty-six months.
-		LockAtMostThirtySixMonths,
+		/// Lock at least for the [`Config::MinLockingMonths`].
+		LockTooShort,
+		/// Lock at most for the [`Config::MaxLockingMonths`].
+		LockTooLong,
 		/// Exceed maximum deposit count.
 		ExceedMaxDeposits,
 		/// Deposit not found.
@@ -172,6 +353,10 @@ pub mod pallet {
 		DepositNotInUse,
 		/// Deposit is already expired.
 		DepositAlreadyExpired,
+		/// Deposit is not expired yet.
+		DepositNotExpired,
+		/// Both parts of a split deposit must be at least the [`Config::MinLockingAmount`].
+		InvalidSplitAmount,
 	}
 
 	/// All deposits.
@@ -182,105 +367,71 @@ pub mod pallet {
 	pub type Deposits<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<Deposit, T::MaxDeposits>>;
 
+	/// The accounts which want their expired deposits to be claimed automatically.
+	///
+	/// An account is removed once all its deposits have been claimed.
+	#[pallet::storage]
+	pub type AutoClaims<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The last account processed by the automatic claiming.
+	///
+	/// The next batch starts after it.
+	#[pallet::storage]
+	pub type AutoClaimCursor<T: Config> = StorageValue<_, T::AccountId>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T>(PhantomData<T>);
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_idle(_: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::auto_claim(remaining_weight)
+		}
+
+		fn integrity_test() {
+			assert!(T::MinLockingMonths::get() <= T::MaxLockingMonths::get());
+			assert!(T::MaxLockingMonths::get() <= MAX_LOCKING_MONTHS);
+		}
+	}
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Lock the RING for some KTON profit/interest.
 		#[pallet::call_index(0)]
-		#[pallet::weight(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::lock())]
 		pub fn lock(origin: OriginFor<T>, amount: Balance, months: u8) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			if amount < T::MinLockingAmount::get() {
-				Err(<Error<T>>::LockAtLeastSome)?;
-			}
-			if months == 0 {
-				Err(<Error<T>>::LockAtLeastOneMonth)?;
-			}
-			if months > MAX_LOCKING_MONTHS {
-				Err(<Error<T>>::LockAtMostThirtySixMonths)?;
-			}
-			if <Deposits<T>>::decode_len(&who).unwrap_or_default() as u32 >= T::MaxDeposits::get() {
-				Err(<Error<T>>::ExceedMaxDeposits)?;
-			}
-
-			let (deposit_id, start_time, expired_time) = <Deposits<T>>::try_mutate(&who, |ds| {
-				let ds = if let Some(ds) = ds {
-					ds
-				} else {
-					<frame_system::Pallet<T>>::inc_consumers(&who)?;
-
-					*ds = Some(Default::default());
-
-					ds.as_mut().expect("[pallet::deposit] `ds` must be some; qed")
-				};
-
-				// Keep the list sorted in increasing order.
-				// And find the missing id.
-				let id = match ds.iter().map(|d| d.id).try_fold(0, |i, id| match i.cmp(&id) {
-					Less => Break(i),
-					Equal => Continue(i + 1),
-					Greater => Break(i - 1),
-				}) {
-					Continue(c) => c,
-					Break(b) => b,
-				};
-				let start_time = Self::now();
-				let expired_time = start_time + MILLISECS_PER_MONTH * months as Moment;
-
-				ds.try_insert(
-					id as _,
-					Deposit { id, value: amount, start_time, expired_time, in_use: false },
-				)
-				.map_err(|_| <Error<T>>::ExceedMaxDeposits)?;
-
-				<Result<_, DispatchError>>::Ok((id, start_time, expired_time))
-			})?;
-
-			T::Ring::transfer(&who, &account_id(), amount, KeepAlive)?;
-
-			let kton_reward = dc_inflation::deposit_interest(amount, months);
-
-			T::Kton::mint(&who, kton_reward)?;
-
-			Self::deposit_event(Event::DepositCreated {
-				owner: who,
-				deposit_id,
-				value: amount,
-				start_time,
-				expired_time,
-				kton_reward,
-			});
+			Self::lock_ring(&who, amount, months)?;
 
 			Ok(())
 		}
 
 		/// Claim the expired-locked RING.
 		#[pallet::call_index(1)]
-		#[pallet::weight(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim())]
 		pub fn claim(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let now = Self::now();
-			let mut claimed = 0;
-			let _ = <Deposits<T>>::try_mutate(&who, |maybe_ds| {
-				let ds = maybe_ds.as_mut().ok_or(())?;
-
-				ds.retain(|d| {
-					if d.expired_time <= now && !d.in_use {
-						claimed += d.value;
-
-						Self::deposit_event(Event::DepositClaimed {
-							owner: who.clone(),
-							deposit_id: d.id,
-						});
-
-						false
-					} else {
-						true
-					}
-				});
+
+			Self::claim_expired_of(&who)?;
+
+			Ok(())
+		}
+
+		/// Claim the unexpired-locked RING of a deposit, which is not in use, by paying the KTON
+		/// penalty.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_with_penalty())]
+		pub fn claim_with_penalty(origin: OriginFor<T>, id: DepositId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let d = <Deposits<T>>::try_mutate(&who, |maybe_ds| {
+				let ds = maybe_ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
+				let i = ds.iter().position(|d| d.id == id).ok_or(<Error<T>>::DepositNotFound)?;
+
+				if ds[i].in_use {
+					Err(<Error<T>>::DepositInUse)?;
+				}
+
+				let d = ds.remove(i);
 
 				if ds.is_empty() {
 					<frame_system::Pallet<T>>::dec_consumers(&who);
@@ -288,23 +439,49 @@ pub mod pallet {
 					*maybe_ds = None;
 				}
 
-				<Result<(), ()>>::Ok(())
-			});
+				<Result<_, DispatchError>>::Ok(d)
+			})?;
+			let now = Self::now();
+
+			if d.expired_time <= now {
+				Err(<Error<T>>::DepositAlreadyExpired)?;
+			}
+
+			let value = d.value;
+			let kton_penalty = Self::settle_early_claim(&who, d, now)?;
 
-			T::Ring::transfer(&account_id(), &who, claimed, AllowDeath)?;
+			T::Ring::transfer(&account_id(), &who, value, AllowDeath)?;
+
+			Self::deposit_event(Event::DepositClaimedWithPenalty {
+				owner: who,
+				deposit_id: id,
+				kton_penalty,
+			});
 
 			Ok(())
 		}
 
-		/// Claim the unexpired-locked RING by paying the KTON penalty.
-		#[pallet::call_index(2)]
-		#[pallet::weight(0)]
-		pub fn claim_with_penalty(origin: OriginFor<T>, id: DepositId) -> DispatchResult {
+		/// Transfer a deposit, which is not in use, to another account.
+		///
+		/// The deposit keeps its value and its locking period, but gets a new id under the
+		/// receiver.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_deposit())]
+		pub fn transfer_deposit(
+			origin: OriginFor<T>,
+			id: DepositId,
+			to: T::AccountId,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let d = <Deposits<T>>::try_mutate(&who, |maybe_ds| {
 				let ds = maybe_ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
-				let d = ds
-					.remove(ds.iter().position(|d| d.id == id).ok_or(<Error<T>>::DepositNotFound)?);
+				let i = ds.iter().position(|d| d.id == id).ok_or(<Error<T>>::DepositNotFound)?;
+
+				if ds[i].in_use {
+					Err(<Error<T>>::DepositInUse)?;
+				}
+
+				let d = ds.remove(i);
 
 				if ds.is_empty() {
 					<frame_system::Pallet<T>>::dec_consumers(&who);
@@ -314,27 +491,291 @@ pub mod pallet {
 
 				<Result<_, DispatchError>>::Ok(d)
 			})?;
+			let new_id = Self::insert_deposit(&to, d)?;
+
+			Self::deposit_event(Event::DepositTransferred {
+				from: who,
+				to,
+				deposit_id: id,
+				new_deposit_id: new_id,
+			});
+
+			Ok(())
+		}
+
+		/// Extend the locking period of an unexpired deposit by `extra_months`.
+		///
+		/// The incremental KTON interest will be minted to the owner.
+		/// This also works while the deposit is in use, the staking backings will be refreshed.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::extend())]
+		pub fn extend(origin: OriginFor<T>, id: DepositId, extra_months: u8) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if extra_months == 0 {
+				Err(<Error<T>>::LockTooShort)?;
+			}
+
+			let now = Self::now();
+			let vesting = T::LinearVesting::get();
+			let (expired_time, kton_reward, in_use) = <Deposits<T>>::try_mutate(&who, |ds| {
+				let ds = ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
+				let d = ds.iter_mut().find(|d| d.id == id).ok_or(<Error<T>>::DepositNotFound)?;
+
+				if d.expired_time <= now {
+					Err(<Error<T>>::DepositAlreadyExpired)?;
+				}
+
+				let promise_m = (d.expired_time - d.start_time) / MILLISECS_PER_MONTH;
+
+				if promise_m + extra_months as Moment > T::MaxLockingMonths::get() as Moment {
+					Err(<Error<T>>::LockTooLong)?;
+				}
+
+				let promise_m = promise_m as u8;
+				let kton_reward = T::DepositInterest::interest(d.value, promise_m + extra_months)
+					.saturating_sub(T::DepositInterest::interest(d.value, promise_m));
+
+				d.expired_time += MILLISECS_PER_MONTH * extra_months as Moment;
+				d.kton_reward += kton_reward;
+
+				if !vesting {
+					d.kton_claimed += kton_reward;
+				}
+
+				<Result<_, DispatchError>>::Ok((d.expired_time, kton_reward, d.in_use))
+			})?;
+
+			if !vesting {
+				T::Kton::mint(&who, kton_reward)?;
+			}
+			if in_use {
+				T::Staking::refresh_backings(&who);
+			}
+
+			Self::deposit_event(Event::DepositExtended {
+				owner: who,
+				deposit_id: id,
+				expired_time,
+				kton_reward,
+			});
+
+			Ok(())
+		}
+
+		/// Lock an expired deposit again for `months`, starting from now.
+		///
+		/// The KTON interest will be minted to the owner.
+		/// This also works while the deposit is in use, the staking backings will be refreshed.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::relock())]
+		pub fn relock(origin: OriginFor<T>, id: DepositId, months: u8) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_locking_months(months)?;
+
+			let start_time = Self::now();
+			let expired_time = start_time + MILLISECS_PER_MONTH * months as Moment;
+			let vesting = T::LinearVesting::get();
+			let (kton_reward, kton_to_mint, in_use) = <Deposits<T>>::try_mutate(&who, |ds| {
+				let ds = ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
+				let d = ds.iter_mut().find(|d| d.id == id).ok_or(<Error<T>>::DepositNotFound)?;
+
+				if d.expired_time > start_time {
+					Err(<Error<T>>::DepositNotExpired)?;
+				}
+
+				// The interest of the last locking period has been fully vested.
+				let kton_unclaimed = d.kton_reward.saturating_sub(d.kton_claimed);
+				let kton_reward = T::DepositInterest::interest(d.value, months);
+
+				d.start_time = start_time;
+				d.expired_time = expired_time;
+				d.kton_reward = kton_reward;
+				d.kton_claimed = if vesting { 0 } else { kton_reward };
+
+				<Result<_, DispatchError>>::Ok((
+					kton_reward,
+					kton_unclaimed + d.kton_claimed,
+					d.in_use,
+				))
+			})?;
+
+			if kton_to_mint != 0 {
+				T::Kton::mint(&who, kton_to_mint)?;
+			}
+			if in_use {
+				T::Staking::refresh_backings(&who);
+			}
+
+			Self::deposit_event(Event::DepositRelocked {
+				owner: who,
+				deposit_id: id,
+				start_time,
+				expired_time,
+				kton_reward,
+			});
+
+			Ok(())
+		}
+
+		/// Split `amount` of a deposit, which is not in use, into a new deposit.
+		///
+		/// Both parts keep the original locking period.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_deposit())]
+		pub fn split_deposit(
+			origin: OriginFor<T>,
+			id: DepositId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let d = Self::carve(&who, id, amount)?;
+			let new_id = Self::insert_deposit(&who, d)?;
+
+			Self::deposit_event(Event::DepositSplit {
+				owner: who,
+				deposit_id: id,
+				new_deposit_id: new_id,
+				value: amount,
+			});
+
+			Ok(())
+		}
+
+		/// Claim `amount` of an unexpired deposit, which is not in use, by paying the KTON
+		/// penalty of the claimed part.
+		///
+		/// The rest of the deposit keeps its id and its locking period.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::partially_claim_with_penalty())]
+		pub fn partially_claim_with_penalty(
+			origin: OriginFor<T>,
+			id: DepositId,
+			amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let d = Self::carve(&who, id, amount)?;
 			let now = Self::now();
 
 			if d.expired_time <= now {
 				Err(<Error<T>>::DepositAlreadyExpired)?;
 			}
 
-			let promise_m = (d.expired_time - d.start_time) / MILLISECS_PER_MONTH;
-			let elapsed_m = (now - d.start_time) / MILLISECS_PER_MONTH;
-			let kton_penalty = dc_inflation::deposit_interest(d.value, promise_m as _)
-				.saturating_sub(dc_inflation::deposit_interest(d.value, elapsed_m as _))
-				.max(1) * 3;
+			let kton_penalty = Self::settle_early_claim(&who, d, now)?;
 
-			T::Kton::burn(&who, kton_penalty)?;
-			Self::deposit_event(Event::DepositClaimedWithPenalty {
+			T::Ring::transfer(&account_id(), &who, amount, AllowDeath)?;
+
+			Self::deposit_event(Event::DepositPartiallyClaimedWithPenalty {
 				owner: who,
 				deposit_id: id,
+				value: amount,
 				kton_penalty,
 			});
 
 			Ok(())
 		}
+
+		/// Claim the given expired deposits, which are not in use.
+		///
+		/// Fail if any of them could not be claimed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_ids(ids.len() as _))]
+		pub fn claim_ids(origin: OriginFor<T>, ids: Vec<DepositId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = Self::now();
+			let claimed = <Deposits<T>>::try_mutate(&who, |maybe_ds| {
+				let ds = maybe_ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
+				let mut claimed = (0, 0);
+
+				for id in &ids {
+					let i =
+						ds.iter().position(|d| d.id == *id).ok_or(<Error<T>>::DepositNotFound)?;
+
+					if ds[i].expired_time > now {
+						Err(<Error<T>>::DepositNotExpired)?;
+					}
+					if ds[i].in_use {
+						Err(<Error<T>>::DepositInUse)?;
+					}
+
+					let d = ds.remove(i);
+
+					claimed.0 += d.value;
+					// An expired deposit's interest has been fully vested.
+					claimed.1 += d.kton_reward.saturating_sub(d.kton_claimed);
+				}
+
+				if ds.is_empty() {
+					<frame_system::Pallet<T>>::dec_consumers(&who);
+
+					*maybe_ds = None;
+				}
+
+				<Result<_, DispatchError>>::Ok(claimed)
+			})?;
+
+			T::Ring::transfer(&account_id(), &who, claimed.0, AllowDeath)?;
+
+			if claimed.1 != 0 {
+				T::Kton::mint(&who, claimed.1)?;
+			}
+
+			ids.into_iter().for_each(|id| {
+				Self::deposit_event(Event::DepositClaimed { owner: who.clone(), deposit_id: id })
+			});
+
+			Ok(())
+		}
+
+		/// Switch the automatic claiming of the expired deposits.
+		///
+		/// If enabled, the expired deposits, which are not in use, will be claimed in the idle
+		/// time of the blocks. It will be disabled once all the deposits have been claimed.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_auto_claim())]
+		pub fn set_auto_claim(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if enabled {
+				<AutoClaims<T>>::insert(&who, ());
+			} else {
+				<AutoClaims<T>>::remove(&who);
+			}
+
+			Self::deposit_event(Event::AutoClaimSet { owner: who, enabled });
+
+			Ok(())
+		}
+
+		/// Claim the vested KTON interest of all the deposits.
+		///
+		/// Only useful under the [`Config::LinearVesting`] mode.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_vested_kton())]
+		pub fn claim_vested_kton(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = Self::now();
+			let amount = <Deposits<T>>::try_mutate(&who, |ds| {
+				let ds = ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
+
+				<Result<_, DispatchError>>::Ok(ds.iter_mut().fold(0, |a, d| {
+					let unclaimed = Self::vested_kton_of(d, now).saturating_sub(d.kton_claimed);
+
+					d.kton_claimed += unclaimed;
+
+					a + unclaimed
+				}))
+			})?;
+
+			if amount != 0 {
+				T::Kton::mint(&who, amount)?;
+			}
+
+			Self::deposit_event(Event::VestedKtonClaimed { owner: who, amount });
+
+			Ok(())
+		}
 	}
 }
 pub use pallet::*;
@@ -346,6 +787,345 @@ where
 	fn now() -> Moment {
 		<pallet_timestamp::Pallet<T> as UnixTime>::now().as_millis()
 	}
+
+	// Claim all the expired deposits, which are not in use, of the given account.
+	//
+	// Return the number of the deposits which have been checked.
+	fn claim_expired_of(who: &T::AccountId) -> Result<u32, DispatchError> {
+		let now = Self::now();
+		let mut checked = 0;
+		let mut claimed = 0;
+		let mut kton_claimed = 0;
+		let _ = <Deposits<T>>::try_mutate(who, |maybe_ds| {
+			let ds = maybe_ds.as_mut().ok_or(())?;
+
+			checked = ds.len() as u32;
+
+			ds.retain(|d| {
+				if d.expired_time <= now && !d.in_use {
+					claimed += d.value;
+					// An expired deposit's interest has been fully vested.
+					kton_claimed += d.kton_reward.saturating_sub(d.kton_claimed);
+
+					Self::deposit_event(Event::DepositClaimed {
+						owner: who.clone(),
+						deposit_id: d.id,
+					});
+
+					false
+				} else {
+					true
+				}
+			});
+
+			if ds.is_empty() {
+				<frame_system::Pallet<T>>::dec_consumers(who);
+
+				*maybe_ds = None;
+			}
+
+			<Result<(), ()>>::Ok(())
+		});
+
+		T::Ring::transfer(&account_id(), who, claimed, AllowDeath)?;
+
+		if kton_claimed != 0 {
+			T::Kton::mint(who, kton_claimed)?;
+		}
+
+		Ok(checked)
+	}
+
+	// Claim the expired deposits of the accounts in [`AutoClaims`], within the weight limit.
+	//
+	// Continue from the [`AutoClaimCursor`], and start over once all the accounts are processed.
+	// An account is charged by its deposits, but it's only processed if the worst case fits.
+	fn auto_claim(limit: Weight) -> Weight {
+		let max_deposits = T::MaxDeposits::get();
+		let max_per_account = <T as Config>::WeightInfo::auto_claim(max_deposits);
+		// Read and write the cursor.
+		let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+
+		if consumed.saturating_add(max_per_account).any_gt(limit) {
+			return Weight::zero();
+		}
+
+		let mut accounts = if let Some(who) = <AutoClaimCursor<T>>::get() {
+			<AutoClaims<T>>::iter_keys_from(<AutoClaims<T>>::hashed_key_for(who))
+		} else {
+			<AutoClaims<T>>::iter_keys()
+		};
+		let mut cursor = None;
+		let mut finished = Vec::new();
+
+		while consumed.saturating_add(max_per_account).all_lte(limit) {
+			let Some(who) = accounts.next() else {
+				cursor = None;
+
+				break;
+			};
+
+			// Roll back the failed one only.
+			let checked =
+				frame_support::storage::with_storage_layer(|| Self::claim_expired_of(&who))
+					.unwrap_or(max_deposits);
+
+			if !<Deposits<T>>::contains_key(&who) {
+				finished.push(who.clone());
+			}
+
+			consumed.saturating_accrue(<T as Config>::WeightInfo::auto_claim(checked));
+			cursor = Some(who);
+		}
+
+		// Remove them after the iteration, there is nothing left to claim.
+		finished.into_iter().for_each(|who| {
+			<AutoClaims<T>>::remove(&who);
+
+			Self::deposit_event(Event::AutoClaimSet { owner: who, enabled: false });
+		});
+
+		if let Some(who) = cursor {
+			<AutoClaimCursor<T>>::put(who);
+		} else {
+			<AutoClaimCursor<T>>::kill();
+		}
+
+		consumed
+	}
+
+	/// Quote the deposits of the given account at the current time.
+	pub fn deposit_quotes_of(who: &T::AccountId) -> Vec<DepositQuote> {
+		let now = Self::now();
+
+		<Deposits<T>>::get(who)
+			.map(|ds| {
+				ds.into_iter()
+					.map(|d| {
+						let expired = d.expired_time <= now;
+						let remaining = d.expired_time.saturating_sub(now);
+
+						DepositQuote {
+							id: d.id,
+							value: d.value,
+							start_time: d.start_time,
+							expired_time: d.expired_time,
+							remaining_months: ((remaining + MILLISECS_PER_MONTH - 1)
+								/ MILLISECS_PER_MONTH) as _,
+							kton_penalty: if expired { 0 } else { Self::kton_penalty_of(&d, now) },
+							claimable_kton: Self::vested_kton_of(&d, now)
+								.saturating_sub(d.kton_claimed),
+							claimable: expired && !d.in_use,
+							in_use: d.in_use,
+						}
+					})
+					.collect()
+			})
+			.unwrap_or_default()
+	}
+
+	/// Quote the KTON interest of locking `amount` RING for `months`.
+	///
+	/// Return zero if the locking period is invalid.
+	pub fn quote_lock(amount: Balance, months: u8) -> Balance {
+		if Self::ensure_locking_months(months).is_err() {
+			0
+		} else {
+			T::DepositInterest::interest(amount, months)
+		}
+	}
+
+	fn lock_ring(
+		who: &T::AccountId,
+		amount: Balance,
+		months: u8,
+	) -> Result<DepositId, DispatchError> {
+		if amount < T::MinLockingAmount::get() {
+			Err(<Error<T>>::LockAtLeastSome)?;
+		}
+		Self::ensure_locking_months(months)?;
+
+		let start_time = Self::now();
+		let expired_time = start_time + MILLISECS_PER_MONTH * months as Moment;
+		let kton_reward = T::DepositInterest::interest(amount, months);
+		let vesting = T::LinearVesting::get();
+		let deposit_id = Self::insert_deposit(
+			who,
+			Deposit {
+				id: 0,
+				value: amount,
+				start_time,
+				expired_time,
+				in_use: false,
+				kton_reward,
+				kton_claimed: if vesting { 0 } else { kton_reward },
+			},
+		)?;
+
+		T::Ring::transfer(who, &account_id(), amount, KeepAlive)?;
+
+		if !vesting {
+			T::Kton::mint(who, kton_reward)?;
+		}
+
+		Self::deposit_event(Event::DepositCreated {
+			owner: who.to_owned(),
+			deposit_id,
+			value: amount,
+			start_time,
+			expired_time,
+			kton_reward,
+		});
+
+		Ok(deposit_id)
+	}
+
+	// Check the locking period against the configured limits.
+	fn ensure_locking_months(months: u8) -> DispatchResult {
+		if months == 0 || months < T::MinLockingMonths::get() {
+			Err(<Error<T>>::LockTooShort)?;
+		}
+		if months > T::MaxLockingMonths::get() {
+			Err(<Error<T>>::LockTooLong)?;
+		}
+
+		Ok(())
+	}
+
+	// Calculate the vested KTON interest of a deposit.
+	fn vested_kton_of(d: &Deposit, now: Moment) -> Balance {
+		if d.expired_time <= now {
+			d.kton_reward
+		} else {
+			Perquintill::from_rational(
+				now.saturating_sub(d.start_time),
+				d.expired_time - d.start_time,
+			)
+			.mul_floor(d.kton_reward)
+		}
+	}
+
+	// Calculate the KTON penalty of claiming an unexpired deposit.
+	//
+	// The unvested interest is forfeited.
+	// Under the linear vesting, the owner pays back the unvested part which has been claimed.
+	// Otherwise, the owner pays a multiple of the unvested interest, counted by months.
+	fn kton_penalty_of(d: &Deposit, now: Moment) -> Balance {
+		if T::LinearVesting::get() {
+			d.kton_claimed.saturating_sub(Self::vested_kton_of(d, now))
+		} else {
+			let promise_m = (d.expired_time - d.start_time) / MILLISECS_PER_MONTH;
+			let elapsed_m = (now - d.start_time) / MILLISECS_PER_MONTH;
+
+			T::DepositInterest::interest(d.value, promise_m as _)
+				.saturating_sub(T::DepositInterest::interest(d.value, elapsed_m as _))
+				.max(1) * T::PenaltyMultiplier::get() as Balance
+		}
+	}
+
+	// Settle the KTON interest of an unexpired deposit, which has been taken out.
+	//
+	// Pay the vested but unclaimed interest, then burn the penalty.
+	// Return the KTON penalty.
+	fn settle_early_claim(
+		who: &T::AccountId,
+		mut d: Deposit,
+		now: Moment,
+	) -> Result<Balance, DispatchError> {
+		let kton_unclaimed = Self::vested_kton_of(&d, now).saturating_sub(d.kton_claimed);
+
+		if kton_unclaimed != 0 {
+			T::Kton::mint(who, kton_unclaimed)?;
+
+			d.kton_claimed += kton_unclaimed;
+		}
+
+		let kton_penalty = Self::kton_penalty_of(&d, now);
+
+		if kton_penalty != 0 {
+			T::Kton::burn(who, kton_penalty)?;
+		}
+
+		Ok(kton_penalty)
+	}
+
+	// Take `amount` from a deposit, which is not in use.
+	//
+	// The KTON interest is split proportionally.
+	// Return the part taken out, which keeps the locking period of the deposit.
+	fn carve(who: &T::AccountId, id: DepositId, amount: Balance) -> Result<Deposit, DispatchError> {
+		<Deposits<T>>::try_mutate(who, |ds| {
+			let ds = ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
+			let d = ds.iter_mut().find(|d| d.id == id).ok_or(<Error<T>>::DepositNotFound)?;
+
+			if d.in_use {
+				Err(<Error<T>>::DepositInUse)?;
+			}
+
+			let min = T::MinLockingAmount::get();
+
+			if amount < min || amount >= d.value || d.value - amount < min {
+				Err(<Error<T>>::InvalidSplitAmount)?;
+			}
+
+			let ratio = Perquintill::from_rational(amount, d.value);
+			let kton_reward = ratio.mul_floor(d.kton_reward);
+			let kton_claimed = ratio.mul_floor(d.kton_claimed);
+
+			d.value -= amount;
+			d.kton_reward -= kton_reward;
+			d.kton_claimed = d.kton_claimed.saturating_sub(kton_claimed);
+
+			Ok(Deposit {
+				id,
+				value: amount,
+				start_time: d.start_time,
+				expired_time: d.expired_time,
+				in_use: false,
+				kton_reward,
+				kton_claimed,
+			})
+		})
+	}
+
+	// Insert a new deposit into the account's list with the smallest missing id.
+	//
+	// The id and the state of the given deposit will be overwritten.
+	fn insert_deposit(who: &T::AccountId, mut d: Deposit) -> Result<DepositId, DispatchError> {
+		if <Deposits<T>>::decode_len(who).unwrap_or_default() as u32 >= T::MaxDeposits::get() {
+			Err(<Error<T>>::ExceedMaxDeposits)?;
+		}
+
+		<Deposits<T>>::try_mutate(who, |ds| {
+			let ds = if let Some(ds) = ds {
+				ds
+			} else {
+				<frame_system::Pallet<T>>::inc_consumers(who)?;
+
+				*ds = Some(Default::default());
+
+				ds.as_mut().expect("[pallet::deposit] `ds` must be some; qed")
+			};
+
+			// Keep the list sorted in increasing order.
+			// And find the missing id.
+			let id = match ds.iter().map(|d| d.id).try_fold(0, |i, id| match i.cmp(&id) {
+				Less => Break(i),
+				Equal => Continue(i + 1),
+				Greater => Break(i - 1),
+			}) {
+				Continue(c) => c,
+				Break(b) => b,
+			};
+
+			d.id = id;
+			d.in_use = false;
+
+			ds.try_insert(id as _, d).map_err(|_| <Error<T>>::ExceedMaxDeposits)?;
+
+			Ok(id)
+		})
+	}
 }
 impl<T> darwinia_staking::Stake for Pallet<T>
 where
@@ -399,6 +1179,43 @@ where
 	}
 }
 
+impl<T> darwinia_staking::Lock for Pallet<T>
+where
+	T: Config,
+{
+	fn lock(
+		who: &Self::AccountId,
+		amount: Balance,
+		months: u8,
+	) -> Result<Self::Item, DispatchError> {
+		Self::lock_ring(who, amount, months)
+	}
+}
+
+impl<T> darwinia_staking::Slash for Pallet<T>
+where
+	T: Config,
+{
+	fn slash(
+		who: &Self::AccountId,
+		item: Self::Item,
+		fraction: Perbill,
+	) -> Result<Balance, DispatchError> {
+		<Deposits<T>>::try_mutate(who, |ds| {
+			let ds = ds.as_mut().ok_or(<Error<T>>::DepositNotFound)?;
+			let d = ds.iter_mut().find(|d| d.id == item).ok_or(<Error<T>>::DepositNotFound)?;
+			let amount = fraction * d.value;
+
+			d.value -= amount;
+
+			// Burn the slashed RING.
+			let _ = T::Ring::slash(&account_id(), amount);
+
+			Ok(amount)
+		})
+	}
+}
+
 /// The account of the deposit pot.
 pub fn account_id<A>() -> A
 where
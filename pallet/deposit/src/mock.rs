@@ -108,12 +108,24 @@ impl darwinia_deposit::SimpleAsset for KtonAsset {
 		Assets::burn(RuntimeOrigin::signed(0), 0.into(), *who, amount)
 	}
 }
+frame_support::parameter_types! {
+	pub static LinearVesting: bool = false;
+	pub static MinLockingMonths: u8 = 1;
+	pub static MaxLockingMonths: u8 = 36;
+	pub static PenaltyMultiplier: u32 = 3;
+}
 impl darwinia_deposit::Config for Runtime {
+	type DepositInterest = ();
 	type Kton = KtonAsset;
+	type LinearVesting = LinearVesting;
 	type MaxDeposits = frame_support::traits::ConstU32<16>;
+	type MaxLockingMonths = MaxLockingMonths;
 	type MinLockingAmount = frame_support::traits::ConstU128<UNIT>;
+	type MinLockingMonths = MinLockingMonths;
+	type PenaltyMultiplier = PenaltyMultiplier;
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type Staking = ();
 	type WeightInfo = ();
 }
 
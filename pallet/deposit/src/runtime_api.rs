@@ -0,0 +1,46 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition of the deposit pallet.
+//!
+//! Provide the computed deposit data, so that the clients don't have to re-implement the
+//! interest/penalty calculations off-chain.
+
+// crates.io
+use codec::Codec;
+// darwinia
+use crate::DepositQuote;
+use dc_types::Balance;
+// substrate
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the deposit data.
+	pub trait DepositApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// Quote the deposits of the given account at the current time.
+		fn deposits_of(who: AccountId) -> Vec<DepositQuote>;
+
+		/// Quote the KTON interest of locking `amount` RING for `months`.
+		///
+		/// Return zero if the locking period is invalid.
+		fn quote_lock(amount: Balance, months: u8) -> Balance;
+	}
+}
@@ -21,9 +21,13 @@ use crate::{
 	mock::{Deposit, *},
 	Deposit as DepositS, *,
 };
-use darwinia_staking::Stake;
+use darwinia_staking::{Stake, StakeExt};
 // substrate
-use frame_support::{assert_noop, assert_ok, traits::Get};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks},
+};
+use sp_runtime::DispatchError;
 
 #[test]
 fn lock_should_work() {
@@ -68,35 +72,45 @@ fn unique_identity_should_work() {
 					value: UNIT,
 					start_time: 0,
 					expired_time: MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(UNIT, 1)
 				},
 				DepositS {
 					id: 1,
 					value: 2 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(2 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(2 * UNIT, 2)
 				},
 				DepositS {
 					id: 2,
 					value: 3 * UNIT,
 					start_time: 0,
 					expired_time: MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(3 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(3 * UNIT, 1)
 				},
 				DepositS {
 					id: 3,
 					value: 4 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(4 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(4 * UNIT, 2)
 				},
 				DepositS {
 					id: 4,
 					value: 5 * UNIT,
 					start_time: 0,
 					expired_time: MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(5 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(5 * UNIT, 1)
 				}
 			]
 		);
@@ -113,21 +127,27 @@ fn unique_identity_should_work() {
 					value: 6 * UNIT,
 					start_time: MILLISECS_PER_MONTH,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(6 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(6 * UNIT, 1)
 				},
 				DepositS {
 					id: 1,
 					value: 2 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(2 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(2 * UNIT, 2)
 				},
 				DepositS {
 					id: 3,
 					value: 4 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(4 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(4 * UNIT, 2)
 				},
 			]
 		);
@@ -141,28 +161,36 @@ fn unique_identity_should_work() {
 					value: 6 * UNIT,
 					start_time: MILLISECS_PER_MONTH,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(6 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(6 * UNIT, 1)
 				},
 				DepositS {
 					id: 1,
 					value: 2 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(2 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(2 * UNIT, 2)
 				},
 				DepositS {
 					id: 2,
 					value: 7 * UNIT,
 					start_time: MILLISECS_PER_MONTH,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(7 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(7 * UNIT, 1)
 				},
 				DepositS {
 					id: 3,
 					value: 4 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(4 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(4 * UNIT, 2)
 				},
 			]
 		);
@@ -176,35 +204,45 @@ fn unique_identity_should_work() {
 					value: 6 * UNIT,
 					start_time: MILLISECS_PER_MONTH,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(6 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(6 * UNIT, 1)
 				},
 				DepositS {
 					id: 1,
 					value: 2 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(2 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(2 * UNIT, 2)
 				},
 				DepositS {
 					id: 2,
 					value: 7 * UNIT,
 					start_time: MILLISECS_PER_MONTH,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(7 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(7 * UNIT, 1)
 				},
 				DepositS {
 					id: 3,
 					value: 4 * UNIT,
 					start_time: 0,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(4 * UNIT, 2),
+					kton_claimed: dc_inflation::deposit_interest(4 * UNIT, 2)
 				},
 				DepositS {
 					id: 4,
 					value: 8 * UNIT,
 					start_time: MILLISECS_PER_MONTH,
 					expired_time: 2 * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(8 * UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(8 * UNIT, 1)
 				},
 			]
 		);
@@ -226,7 +264,9 @@ fn expire_time_should_work() {
 					value: UNIT,
 					start_time: (i - 1) as Moment * MILLISECS_PER_MONTH,
 					expired_time: i as Moment * MILLISECS_PER_MONTH,
-					in_use: false
+					in_use: false,
+					kton_reward: dc_inflation::deposit_interest(UNIT, 1),
+					kton_claimed: dc_inflation::deposit_interest(UNIT, 1)
 				})
 				.collect::<Vec<_>>()
 				.as_slice()
@@ -244,12 +284,12 @@ fn lock_should_fail() {
 
 		assert_noop!(
 			Deposit::lock(RuntimeOrigin::signed(1), UNIT, 0),
-			<Error<Runtime>>::LockAtLeastOneMonth
+			<Error<Runtime>>::LockTooShort
 		);
 
 		assert_noop!(
 			Deposit::lock(RuntimeOrigin::signed(1), UNIT, 37),
-			<Error<Runtime>>::LockAtMostThirtySixMonths
+			<Error<Runtime>>::LockTooLong
 		);
 
 		(0..<<Runtime as Config>::MaxDeposits as Get<_>>::get()).for_each(|_| {
@@ -311,6 +351,7 @@ fn claim_with_penalty_should_work() {
 		assert!(Deposit::deposit_of(&1).is_none());
 		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
 		assert!(Deposit::deposit_of(&1).is_some());
+		assert_eq!(Balances::free_balance(&1), 999 * UNIT);
 
 		assert_noop!(
 			Deposit::claim_with_penalty(RuntimeOrigin::signed(1), 0),
@@ -318,8 +359,16 @@ fn claim_with_penalty_should_work() {
 		);
 
 		assert_ok!(KtonAsset::mint(&1, UNIT));
+		assert_ok!(Deposit::stake(&1, 0));
+		assert_noop!(
+			Deposit::claim_with_penalty(RuntimeOrigin::signed(1), 0),
+			<Error<Runtime>>::DepositInUse
+		);
+
+		assert_ok!(Deposit::unstake(&1, 0));
 		assert_ok!(Deposit::claim_with_penalty(RuntimeOrigin::signed(1), 0));
 		assert_eq!(Assets::balance(0, 1), 999_984_771_573_604_062);
+		assert_eq!(Balances::free_balance(&1), 1_000 * UNIT);
 		assert!(Deposit::deposit_of(&1).is_none());
 
 		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
@@ -332,3 +381,599 @@ fn claim_with_penalty_should_work() {
 		);
 	});
 }
+
+#[test]
+fn transfer_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 2 * UNIT, 2));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(2), UNIT, 1));
+		efflux(MILLISECS_PER_MONTH / 2);
+
+		assert_noop!(
+			Deposit::transfer_deposit(RuntimeOrigin::signed(1), 2, 2),
+			<Error<Runtime>>::DepositNotFound
+		);
+
+		assert_ok!(Deposit::stake(&1, 1));
+		assert_noop!(
+			Deposit::transfer_deposit(RuntimeOrigin::signed(1), 1, 2),
+			<Error<Runtime>>::DepositInUse
+		);
+		assert_ok!(Deposit::unstake(&1, 1));
+
+		// The receiver gets a new id, and the locking period is kept.
+		assert_ok!(Deposit::transfer_deposit(RuntimeOrigin::signed(1), 1, 2));
+		assert_eq!(Deposit::deposit_of(&1).unwrap().iter().map(|d| d.id).collect::<Vec<_>>(), [0]);
+		assert_eq!(
+			Deposit::deposit_of(&2).unwrap()[1],
+			DepositS {
+				id: 1,
+				value: 2 * UNIT,
+				start_time: 0,
+				expired_time: 2 * MILLISECS_PER_MONTH,
+				in_use: false,
+				kton_reward: dc_inflation::deposit_interest(2 * UNIT, 2),
+				kton_claimed: dc_inflation::deposit_interest(2 * UNIT, 2)
+			}
+		);
+		assert_eq!(System::account(&1).consumers, 1);
+		assert_eq!(System::account(&2).consumers, 1);
+
+		assert_ok!(Deposit::transfer_deposit(RuntimeOrigin::signed(1), 0, 2));
+		assert!(Deposit::deposit_of(&1).is_none());
+		assert_eq!(
+			Deposit::deposit_of(&2).unwrap().iter().map(|d| d.id).collect::<Vec<_>>(),
+			[0, 1, 2]
+		);
+		assert_eq!(System::account(&1).consumers, 0);
+		assert_eq!(System::account(&2).consumers, 1);
+		assert_eq!(Balances::free_balance(&darwinia_deposit::account_id()), 4 * UNIT);
+	});
+}
+
+#[test]
+fn transfer_deposit_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+
+		// The receiver must exist.
+		assert_noop!(
+			Deposit::transfer_deposit(RuntimeOrigin::signed(1), 0, 3),
+			DispatchError::NoProviders
+		);
+
+		(0..<<Runtime as Config>::MaxDeposits as Get<_>>::get()).for_each(|_| {
+			assert_ok!(Deposit::lock(RuntimeOrigin::signed(2), UNIT, 1));
+		});
+		assert_noop!(
+			Deposit::transfer_deposit(RuntimeOrigin::signed(1), 0, 2),
+			<Error<Runtime>>::ExceedMaxDeposits
+		);
+	});
+}
+
+#[test]
+fn extend_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_ok!(Deposit::stake(&1, 0));
+
+		let kton = Assets::balance(0, 1);
+
+		assert_noop!(
+			Deposit::extend(RuntimeOrigin::signed(1), 0, 0),
+			<Error<Runtime>>::LockTooShort
+		);
+		assert_noop!(
+			Deposit::extend(RuntimeOrigin::signed(1), 0, MAX_LOCKING_MONTHS),
+			<Error<Runtime>>::LockTooLong
+		);
+		assert_noop!(
+			Deposit::extend(RuntimeOrigin::signed(1), 1, 1),
+			<Error<Runtime>>::DepositNotFound
+		);
+
+		// The incremental interest is minted, even if the deposit is in use.
+		assert_ok!(Deposit::extend(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(
+			Assets::balance(0, 1) - kton,
+			dc_inflation::deposit_interest(UNIT, 3) - dc_inflation::deposit_interest(UNIT, 1)
+		);
+		assert_eq!(
+			Deposit::deposit_of(&1).unwrap()[0],
+			DepositS {
+				id: 0,
+				value: UNIT,
+				start_time: 0,
+				expired_time: 3 * MILLISECS_PER_MONTH,
+				in_use: true,
+				kton_reward: dc_inflation::deposit_interest(UNIT, 3),
+				kton_claimed: dc_inflation::deposit_interest(UNIT, 3)
+			}
+		);
+
+		efflux(3 * MILLISECS_PER_MONTH);
+		assert_noop!(
+			Deposit::extend(RuntimeOrigin::signed(1), 0, 1),
+			<Error<Runtime>>::DepositAlreadyExpired
+		);
+	});
+}
+
+#[test]
+fn relock_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_ok!(Deposit::stake(&1, 0));
+		assert_noop!(
+			Deposit::relock(RuntimeOrigin::signed(1), 0, 1),
+			<Error<Runtime>>::DepositNotExpired
+		);
+
+		efflux(2 * MILLISECS_PER_MONTH);
+
+		let kton = Assets::balance(0, 1);
+
+		assert_noop!(
+			Deposit::relock(RuntimeOrigin::signed(1), 0, 0),
+			<Error<Runtime>>::LockTooShort
+		);
+		assert_noop!(
+			Deposit::relock(RuntimeOrigin::signed(1), 0, MaxLockingMonths::get() + 1),
+			<Error<Runtime>>::LockTooLong
+		);
+
+		// The deposit keeps its id, even if it is in use.
+		assert_ok!(Deposit::relock(RuntimeOrigin::signed(1), 0, 2));
+		assert_eq!(Assets::balance(0, 1) - kton, dc_inflation::deposit_interest(UNIT, 2));
+		assert_eq!(
+			Deposit::deposit_of(&1).unwrap()[0],
+			DepositS {
+				id: 0,
+				value: UNIT,
+				start_time: 2 * MILLISECS_PER_MONTH,
+				expired_time: 4 * MILLISECS_PER_MONTH,
+				in_use: true,
+				kton_reward: dc_inflation::deposit_interest(UNIT, 2),
+				kton_claimed: dc_inflation::deposit_interest(UNIT, 2)
+			}
+		);
+
+		// The relocked deposit is not claimable until it expires again.
+		assert_ok!(Deposit::unstake(&1, 0));
+		assert_ok!(Deposit::claim(RuntimeOrigin::signed(1)));
+		assert!(Deposit::deposit_of(&1).is_some());
+	});
+}
+
+#[test]
+fn split_deposit_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 10 * UNIT, 2));
+
+		let kton_reward = dc_inflation::deposit_interest(10 * UNIT, 2);
+
+		efflux(MILLISECS_PER_MONTH);
+
+		assert_ok!(Deposit::stake(&1, 0));
+		assert_noop!(
+			Deposit::split_deposit(RuntimeOrigin::signed(1), 0, 4 * UNIT),
+			<Error<Runtime>>::DepositInUse
+		);
+		assert_ok!(Deposit::unstake(&1, 0));
+
+		// Both parts must be at least `MinLockingAmount`.
+		assert_noop!(
+			Deposit::split_deposit(RuntimeOrigin::signed(1), 0, UNIT - 1),
+			<Error<Runtime>>::InvalidSplitAmount
+		);
+		assert_noop!(
+			Deposit::split_deposit(RuntimeOrigin::signed(1), 0, 10 * UNIT - 1),
+			<Error<Runtime>>::InvalidSplitAmount
+		);
+		assert_noop!(
+			Deposit::split_deposit(RuntimeOrigin::signed(1), 0, 10 * UNIT),
+			<Error<Runtime>>::InvalidSplitAmount
+		);
+
+		assert_ok!(Deposit::split_deposit(RuntimeOrigin::signed(1), 0, 4 * UNIT));
+		assert_eq!(
+			Deposit::deposit_of(&1).unwrap().as_slice(),
+			&[
+				DepositS {
+					id: 0,
+					value: 6 * UNIT,
+					start_time: 0,
+					expired_time: 2 * MILLISECS_PER_MONTH,
+					in_use: false,
+					kton_reward: kton_reward - kton_reward * 2 / 5,
+					kton_claimed: kton_reward - kton_reward * 2 / 5
+				},
+				DepositS {
+					id: 1,
+					value: 4 * UNIT,
+					start_time: 0,
+					expired_time: 2 * MILLISECS_PER_MONTH,
+					in_use: false,
+					kton_reward: kton_reward * 2 / 5,
+					kton_claimed: kton_reward * 2 / 5
+				},
+			]
+		);
+		assert_eq!(Deposit::amount(&1, 0), Ok(6 * UNIT));
+		assert_eq!(Deposit::amount(&1, 1), Ok(4 * UNIT));
+		assert_eq!(Balances::free_balance(&darwinia_deposit::account_id()), 10 * UNIT);
+	});
+}
+
+#[test]
+fn partially_claim_with_penalty_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 10 * UNIT, 2));
+
+		let kton_reward = dc_inflation::deposit_interest(10 * UNIT, 2);
+
+		assert_ok!(KtonAsset::mint(&1, UNIT));
+		efflux(MILLISECS_PER_MONTH);
+
+		assert_ok!(Deposit::stake(&1, 0));
+		assert_noop!(
+			Deposit::partially_claim_with_penalty(RuntimeOrigin::signed(1), 0, 4 * UNIT),
+			<Error<Runtime>>::DepositInUse
+		);
+		assert_ok!(Deposit::unstake(&1, 0));
+
+		let kton = Assets::balance(0, 1);
+
+		// Only the claimed part is charged.
+		assert_ok!(Deposit::partially_claim_with_penalty(RuntimeOrigin::signed(1), 0, 4 * UNIT));
+		assert_eq!(
+			kton - Assets::balance(0, 1),
+			(dc_inflation::deposit_interest(4 * UNIT, 2)
+				- dc_inflation::deposit_interest(4 * UNIT, 1))
+				* 3
+		);
+		assert_eq!(Balances::free_balance(&1), 994 * UNIT);
+		assert_eq!(
+			Deposit::deposit_of(&1).unwrap().as_slice(),
+			&[DepositS {
+				id: 0,
+				value: 6 * UNIT,
+				start_time: 0,
+				expired_time: 2 * MILLISECS_PER_MONTH,
+				in_use: false,
+				kton_reward: kton_reward - kton_reward * 2 / 5,
+				kton_claimed: kton_reward - kton_reward * 2 / 5
+			}]
+		);
+
+		assert_noop!(
+			Deposit::partially_claim_with_penalty(RuntimeOrigin::signed(1), 0, 6 * UNIT),
+			<Error<Runtime>>::InvalidSplitAmount
+		);
+
+		efflux(MILLISECS_PER_MONTH);
+		assert_noop!(
+			Deposit::partially_claim_with_penalty(RuntimeOrigin::signed(1), 0, UNIT),
+			<Error<Runtime>>::DepositAlreadyExpired
+		);
+	});
+}
+
+#[test]
+fn deposit_quotes_should_work() {
+	new_test_ext().execute_with(|| {
+		assert!(Deposit::deposit_quotes_of(&1).is_empty());
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 2 * UNIT, 2));
+		assert_ok!(Deposit::stake(&1, 1));
+		assert_eq!(
+			Deposit::deposit_quotes_of(&1),
+			vec![
+				DepositQuote {
+					id: 0,
+					value: UNIT,
+					start_time: 0,
+					expired_time: MILLISECS_PER_MONTH,
+					remaining_months: 1,
+					kton_penalty: 7_614_213_197_969 * 3,
+					claimable_kton: 0,
+					claimable: false,
+					in_use: false
+				},
+				DepositQuote {
+					id: 1,
+					value: 2 * UNIT,
+					start_time: 0,
+					expired_time: 2 * MILLISECS_PER_MONTH,
+					remaining_months: 2,
+					kton_penalty: dc_inflation::deposit_interest(2 * UNIT, 2) * 3,
+					claimable_kton: 0,
+					claimable: false,
+					in_use: true
+				}
+			]
+		);
+
+		efflux(MILLISECS_PER_MONTH + 1);
+		let quotes = Deposit::deposit_quotes_of(&1);
+		assert_eq!(
+			(quotes[0].remaining_months, quotes[0].kton_penalty, quotes[0].claimable),
+			(0, 0, true)
+		);
+		assert_eq!(
+			(quotes[1].remaining_months, quotes[1].kton_penalty, quotes[1].claimable),
+			(
+				1,
+				(dc_inflation::deposit_interest(2 * UNIT, 2)
+					- dc_inflation::deposit_interest(2 * UNIT, 1))
+					* 3,
+				false
+			)
+		);
+
+		assert_eq!(Deposit::quote_lock(UNIT, 1), 7_614_213_197_969);
+		assert_eq!(Deposit::quote_lock(UNIT, 0), 0);
+		assert_eq!(Deposit::quote_lock(UNIT, MaxLockingMonths::get() + 1), 0);
+	});
+}
+
+#[test]
+fn claim_ids_should_work() {
+	new_test_ext().execute_with(|| {
+		(0..3).for_each(|_| assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1)));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 2));
+		assert_ok!(Deposit::stake(&1, 1));
+		assert_eq!(Balances::free_balance(&1), 996 * UNIT);
+
+		efflux(MILLISECS_PER_MONTH);
+		assert_ok!(Deposit::claim_ids(RuntimeOrigin::signed(1), vec![2, 0]));
+		assert_eq!(Balances::free_balance(&1), 998 * UNIT);
+		assert_eq!(
+			Deposit::deposit_of(&1).unwrap().iter().map(|d| d.id).collect::<Vec<_>>(),
+			vec![1, 3]
+		);
+		assert_eq!(System::account(&1).consumers, 1);
+
+		assert_ok!(Deposit::unstake(&1, 1));
+		efflux(MILLISECS_PER_MONTH);
+		assert_ok!(Deposit::claim_ids(RuntimeOrigin::signed(1), vec![1, 3]));
+		assert_eq!(Balances::free_balance(&1), 1_000 * UNIT);
+		assert!(Deposit::deposit_of(&1).is_none());
+		assert_eq!(System::account(&1).consumers, 0);
+	});
+}
+
+#[test]
+fn claim_ids_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Deposit::claim_ids(RuntimeOrigin::signed(1), vec![0]),
+			<Error<Runtime>>::DepositNotFound
+		);
+
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 2));
+		assert_noop!(
+			Deposit::claim_ids(RuntimeOrigin::signed(1), vec![0]),
+			<Error<Runtime>>::DepositNotExpired
+		);
+
+		efflux(MILLISECS_PER_MONTH);
+		assert_noop!(
+			Deposit::claim_ids(RuntimeOrigin::signed(1), vec![0, 1]),
+			<Error<Runtime>>::DepositNotExpired
+		);
+		assert_noop!(
+			Deposit::claim_ids(RuntimeOrigin::signed(1), vec![0, 0]),
+			<Error<Runtime>>::DepositNotFound
+		);
+
+		assert_ok!(Deposit::stake(&1, 0));
+		assert_noop!(
+			Deposit::claim_ids(RuntimeOrigin::signed(1), vec![0]),
+			<Error<Runtime>>::DepositInUse
+		);
+	});
+}
+
+#[test]
+fn auto_claim_should_work() {
+	new_test_ext().execute_with(|| {
+		(1..=2).for_each(|who| {
+			assert_ok!(Deposit::lock(RuntimeOrigin::signed(who), UNIT, 1));
+			assert_ok!(Deposit::set_auto_claim(RuntimeOrigin::signed(who), true));
+		});
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(2), UNIT, 2));
+
+		let per_account = |deposits| <() as WeightInfo>::auto_claim(deposits);
+		let max_per_account = per_account(<Runtime as Config>::MaxDeposits::get());
+		let claimed_count = || {
+			(1..=2)
+				.filter(|who| {
+					Deposit::deposit_of(who).map(|ds| ds.iter().all(|d| d.id != 0)).unwrap_or(true)
+				})
+				.count()
+		};
+
+		efflux(MILLISECS_PER_MONTH);
+		assert_eq!(Deposit::on_idle(0, max_per_account - Weight::from_ref_time(1)), Weight::zero());
+		assert_eq!(claimed_count(), 0);
+		assert!(!<AutoClaimCursor<Runtime>>::exists());
+
+		// Charged by the deposits of the account.
+		let consumed = Deposit::on_idle(0, max_per_account);
+		assert!(consumed == per_account(1) || consumed == per_account(2));
+		assert_eq!(claimed_count(), 1);
+		assert!(<AutoClaimCursor<Runtime>>::exists());
+
+		assert_eq!(
+			Deposit::on_idle(0, max_per_account) + consumed,
+			per_account(1) + per_account(2)
+		);
+		assert_eq!(claimed_count(), 2);
+		assert!(<AutoClaimCursor<Runtime>>::exists());
+
+		Deposit::on_idle(0, max_per_account);
+		assert!(!<AutoClaimCursor<Runtime>>::exists());
+		assert_eq!(Balances::free_balance(&1), 1_000 * UNIT);
+		assert_eq!(Balances::free_balance(&2), 1_999 * UNIT);
+		assert!(Deposit::deposit_of(&1).is_none());
+		assert_eq!(Deposit::deposit_of(&2).unwrap().len(), 1);
+
+		// The account without any deposit left is removed.
+		assert!(!<AutoClaims<Runtime>>::contains_key(&1));
+		assert!(<AutoClaims<Runtime>>::contains_key(&2));
+
+		assert_ok!(Deposit::set_auto_claim(RuntimeOrigin::signed(2), false));
+		assert!(!<AutoClaims<Runtime>>::contains_key(&2));
+	});
+}
+
+#[test]
+fn linear_vesting_should_work() {
+	new_test_ext().execute_with(|| {
+		LinearVesting::set(true);
+
+		let kton_reward = dc_inflation::deposit_interest(10 * UNIT, 2);
+
+		// Nothing is paid upfront.
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 10 * UNIT, 2));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Deposit::deposit_of(&1).unwrap()[0].kton_reward, kton_reward);
+		assert_eq!(Deposit::deposit_of(&1).unwrap()[0].kton_claimed, 0);
+
+		// The accrued interest is claimable at any time.
+		efflux(MILLISECS_PER_MONTH);
+		assert_eq!(Deposit::deposit_quotes_of(&1)[0].claimable_kton, kton_reward / 2);
+		assert_eq!(Deposit::deposit_quotes_of(&1)[0].kton_penalty, 0);
+		assert_ok!(Deposit::claim_vested_kton(RuntimeOrigin::signed(1)));
+		assert_eq!(Assets::balance(0, 1), kton_reward / 2);
+		assert_ok!(Deposit::claim_vested_kton(RuntimeOrigin::signed(1)));
+		assert_eq!(Assets::balance(0, 1), kton_reward / 2);
+
+		// The rest is paid while claiming the expired deposit.
+		efflux(MILLISECS_PER_MONTH);
+		assert_ok!(Deposit::claim(RuntimeOrigin::signed(1)));
+		assert_eq!(Assets::balance(0, 1), kton_reward);
+		assert!(Deposit::deposit_of(&1).is_none());
+	});
+	new_test_ext().execute_with(|| {
+		LinearVesting::set(true);
+
+		let kton_reward = dc_inflation::deposit_interest(10 * UNIT, 2);
+
+		// An early claim only forfeits the unvested part.
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 10 * UNIT, 2));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 10 * UNIT, 2));
+		efflux(MILLISECS_PER_MONTH);
+		assert_ok!(Deposit::claim_with_penalty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::balance(0, 1), kton_reward / 2);
+
+		// Only the claimed part is settled.
+		assert_ok!(Deposit::partially_claim_with_penalty(RuntimeOrigin::signed(1), 1, 4 * UNIT));
+		assert_eq!(Assets::balance(0, 1), kton_reward / 2 + kton_reward * 2 / 5 / 2);
+		assert_eq!(Balances::free_balance(&1), 994 * UNIT);
+		assert_eq!(
+			Deposit::deposit_of(&1).unwrap()[0].kton_reward,
+			kton_reward - kton_reward * 2 / 5
+		);
+	});
+}
+
+#[test]
+fn linear_vesting_penalty_should_work() {
+	new_test_ext().execute_with(|| {
+		let kton_reward = dc_inflation::deposit_interest(10 * UNIT, 2);
+
+		// The interest of the deposits created before the switching has been paid upfront.
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 10 * UNIT, 2));
+		assert_eq!(Assets::balance(0, 1), kton_reward);
+
+		LinearVesting::set(true);
+		efflux(MILLISECS_PER_MONTH);
+		assert_eq!(Deposit::deposit_quotes_of(&1)[0].claimable_kton, 0);
+		assert_eq!(Deposit::deposit_quotes_of(&1)[0].kton_penalty, kton_reward - kton_reward / 2);
+
+		// Pay back the claimed but unvested part.
+		assert_ok!(Deposit::claim_with_penalty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::balance(0, 1), kton_reward / 2);
+	});
+}
+
+#[test]
+fn legacy_deposit_conversion_should_work() {
+	assert_eq!(
+		DepositS::from(LegacyDeposit {
+			id: 1,
+			value: 10 * UNIT,
+			start_time: MILLISECS_PER_MONTH,
+			expired_time: 3 * MILLISECS_PER_MONTH,
+			in_use: true
+		}),
+		DepositS {
+			id: 1,
+			value: 10 * UNIT,
+			start_time: MILLISECS_PER_MONTH,
+			expired_time: 3 * MILLISECS_PER_MONTH,
+			in_use: true,
+			kton_reward: dc_inflation::deposit_interest(10 * UNIT, 2),
+			kton_claimed: dc_inflation::deposit_interest(10 * UNIT, 2)
+		}
+	);
+}
+
+#[test]
+fn locking_months_limits_should_work() {
+	new_test_ext().execute_with(|| {
+		MinLockingMonths::set(3);
+		MaxLockingMonths::set(12);
+
+		assert_noop!(
+			Deposit::lock(RuntimeOrigin::signed(1), UNIT, 2),
+			<Error<Runtime>>::LockTooShort
+		);
+		assert_noop!(
+			Deposit::lock(RuntimeOrigin::signed(1), UNIT, 13),
+			<Error<Runtime>>::LockTooLong
+		);
+		assert_eq!(Deposit::quote_lock(UNIT, 2), 0);
+		assert_eq!(Deposit::quote_lock(UNIT, 13), 0);
+		assert_eq!(Deposit::quote_lock(UNIT, 12), dc_inflation::deposit_interest(UNIT, 12));
+
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 3));
+		assert_noop!(
+			Deposit::extend(RuntimeOrigin::signed(1), 0, 10),
+			<Error<Runtime>>::LockTooLong
+		);
+		assert_ok!(Deposit::extend(RuntimeOrigin::signed(1), 0, 9));
+
+		efflux(12 * MILLISECS_PER_MONTH);
+		assert_noop!(
+			Deposit::relock(RuntimeOrigin::signed(1), 0, 2),
+			<Error<Runtime>>::LockTooShort
+		);
+		assert_ok!(Deposit::relock(RuntimeOrigin::signed(1), 0, 3));
+	});
+}
+
+#[test]
+fn penalty_multiplier_should_work() {
+	new_test_ext().execute_with(|| {
+		PenaltyMultiplier::set(1);
+
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_eq!(Assets::balance(0, 1), dc_inflation::deposit_interest(UNIT, 1));
+		assert_eq!(
+			Deposit::deposit_quotes_of(&1)[0].kton_penalty,
+			dc_inflation::deposit_interest(UNIT, 1)
+		);
+
+		// Only the received interest is charged.
+		assert_ok!(Deposit::claim_with_penalty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Balances::free_balance(&1), 1_000 * UNIT);
+		assert!(Deposit::deposit_of(&1).is_none());
+	});
+}
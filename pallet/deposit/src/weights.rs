@@ -16,15 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for darwinia_deposit
+//! Weights for `darwinia_deposit`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-02-22, STEPS: `2`, REPEAT: `1`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `inv.cafe`, CPU: `13th Gen Intel(R) Core(TM) i9-13900K`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("pangolin-local"), DB CACHE: 1024
+//! Only `lock`, `claim` and `claim_with_penalty` were measured by the Substrate benchmark CLI
+//! (DATE: 2023-02-22, STEPS: `2`, REPEAT: `1`, HOSTNAME: `inv.cafe`), against the previous
+//! `Deposit` layout, and have been adjusted to the current storage accesses since.
+//! THE OTHER WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, estimated from the storage accesses of the
+//! benchmarks. Regenerate this file with the command below before going to production.
 
-// Executed Command:
+// Regenerate with:
 // target/release/darwinia
 // benchmark
 // pallet
@@ -44,6 +44,10 @@
 // *
 // --pallet
 // darwinia-deposit
+// --steps
+// 50
+// --repeat
+// 20
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(missing_docs)]
@@ -58,16 +62,25 @@ pub trait WeightInfo {
 	fn lock() -> Weight;
 	fn claim() -> Weight;
 	fn claim_with_penalty() -> Weight;
+	fn transfer_deposit() -> Weight;
+	fn extend() -> Weight;
+	fn relock() -> Weight;
+	fn split_deposit() -> Weight;
+	fn partially_claim_with_penalty() -> Weight;
+	fn claim_ids(x: u32, ) -> Weight;
+	fn set_auto_claim() -> Weight;
+	fn auto_claim(x: u32, ) -> Weight;
+	fn claim_vested_kton() -> Weight;
 }
 
 /// Weights for darwinia_deposit using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
@@ -76,44 +89,220 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn lock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3208`
-		//  Estimated: `11632`
+		//  Estimated: `14735`
 		// Minimum execution time: 51_636 nanoseconds.
-		Weight::from_ref_time(51_636_000)
-			.saturating_add(Weight::from_proof_size(11632))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		Weight::from_ref_time(66_636_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	fn claim() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2906`
-		//  Estimated: `6422`
+		//  Estimated: `14735`
 		// Minimum execution time: 73_239 nanoseconds.
-		Weight::from_ref_time(73_239_000)
-			.saturating_add(Weight::from_proof_size(6422))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		Weight::from_ref_time(108_239_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn claim_with_penalty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2420`
-		//  Estimated: `9041`
+		//  Estimated: `14735`
 		// Minimum execution time: 52_162 nanoseconds.
-		Weight::from_ref_time(52_162_000)
-			.saturating_add(Weight::from_proof_size(9041))
+		Weight::from_ref_time(77_162_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Deposit Deposits (r:2 w:2)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn transfer_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2874`
+		//  Estimated: `12862`
+		// Minimum execution time: 31_204 nanoseconds.
+		Weight::from_ref_time(31_204_000)
+			.saturating_add(Weight::from_proof_size(12862))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn extend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `96585`
+		// Minimum execution time: 111_517 nanoseconds.
+		Weight::from_ref_time(111_517_000)
+			.saturating_add(Weight::from_proof_size(96585))
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(35_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn relock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `96585`
+		// Minimum execution time: 110_863 nanoseconds.
+		Weight::from_ref_time(110_863_000)
+			.saturating_add(Weight::from_proof_size(96585))
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(35_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	fn split_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `3840`
+		// Minimum execution time: 22_374 nanoseconds.
+		Weight::from_ref_time(22_374_000)
+			.saturating_add(Weight::from_proof_size(3840))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn partially_claim_with_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2603`
+		//  Estimated: `14735`
+		// Minimum execution time: 67_921 nanoseconds.
+		Weight::from_ref_time(67_921_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_ids(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2906`
+		//  Estimated: `6934`
+		// Minimum execution time: 79_411 nanoseconds.
+		Weight::from_ref_time(79_411_000)
+			.saturating_add(Weight::from_proof_size(6934))
+			// Standard Error: 247
+			.saturating_add(Weight::from_ref_time(1_735).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Deposit AutoClaims (r:0 w:1)
+	/// Proof: Deposit AutoClaims (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 11_893 nanoseconds.
+		Weight::from_ref_time(11_893_000)
+			.saturating_add(Weight::from_proof_size(0))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Deposit AutoClaims (r:1 w:1)
+	/// Proof: Deposit AutoClaims (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn auto_claim(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3240`
+		//  Estimated: `17246`
+		// Minimum execution time: 87_919 nanoseconds.
+		Weight::from_ref_time(87_919_000)
+			.saturating_add(Weight::from_proof_size(17246))
+			.saturating_add(Weight::from_ref_time(1_520_000).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn claim_vested_kton() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2573`
+		//  Estimated: `9553`
+		// Minimum execution time: 37_812 nanoseconds.
+		Weight::from_ref_time(37_812_000)
+			.saturating_add(Weight::from_proof_size(9553))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
@@ -122,10 +311,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
@@ -134,44 +323,220 @@ impl WeightInfo for () {
 	fn lock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3208`
-		//  Estimated: `11632`
+		//  Estimated: `14735`
 		// Minimum execution time: 51_636 nanoseconds.
-		Weight::from_ref_time(51_636_000)
-			.saturating_add(Weight::from_proof_size(11632))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		Weight::from_ref_time(66_636_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	fn claim() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2906`
-		//  Estimated: `6422`
+		//  Estimated: `14735`
 		// Minimum execution time: 73_239 nanoseconds.
-		Weight::from_ref_time(73_239_000)
-			.saturating_add(Weight::from_proof_size(6422))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		Weight::from_ref_time(108_239_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn claim_with_penalty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2420`
-		//  Estimated: `9041`
+		//  Estimated: `14735`
 		// Minimum execution time: 52_162 nanoseconds.
-		Weight::from_ref_time(52_162_000)
-			.saturating_add(Weight::from_proof_size(9041))
+		Weight::from_ref_time(77_162_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Deposit Deposits (r:2 w:2)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn transfer_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2874`
+		//  Estimated: `12862`
+		// Minimum execution time: 31_204 nanoseconds.
+		Weight::from_ref_time(31_204_000)
+			.saturating_add(Weight::from_proof_size(12862))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn extend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `96585`
+		// Minimum execution time: 111_517 nanoseconds.
+		Weight::from_ref_time(111_517_000)
+			.saturating_add(Weight::from_proof_size(96585))
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(35_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn relock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `96585`
+		// Minimum execution time: 110_863 nanoseconds.
+		Weight::from_ref_time(110_863_000)
+			.saturating_add(Weight::from_proof_size(96585))
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(35_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	fn split_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `3840`
+		// Minimum execution time: 22_374 nanoseconds.
+		Weight::from_ref_time(22_374_000)
+			.saturating_add(Weight::from_proof_size(3840))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn partially_claim_with_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2603`
+		//  Estimated: `14735`
+		// Minimum execution time: 67_921 nanoseconds.
+		Weight::from_ref_time(67_921_000)
+			.saturating_add(Weight::from_proof_size(14735))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_ids(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2906`
+		//  Estimated: `6934`
+		// Minimum execution time: 79_411 nanoseconds.
+		Weight::from_ref_time(79_411_000)
+			.saturating_add(Weight::from_proof_size(6934))
+			// Standard Error: 247
+			.saturating_add(Weight::from_ref_time(1_735).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Deposit AutoClaims (r:0 w:1)
+	/// Proof: Deposit AutoClaims (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 11_893 nanoseconds.
+		Weight::from_ref_time(11_893_000)
+			.saturating_add(Weight::from_proof_size(0))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Deposit AutoClaims (r:1 w:1)
+	/// Proof: Deposit AutoClaims (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn auto_claim(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3240`
+		//  Estimated: `17246`
+		// Minimum execution time: 87_919 nanoseconds.
+		Weight::from_ref_time(87_919_000)
+			.saturating_add(Weight::from_proof_size(17246))
+			.saturating_add(Weight::from_ref_time(1_520_000).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn claim_vested_kton() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2573`
+		//  Estimated: `9553`
+		// Minimum execution time: 37_812 nanoseconds.
+		Weight::from_ref_time(37_812_000)
+			.saturating_add(Weight::from_proof_size(9553))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
@@ -0,0 +1,172 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+// substrate
+use frame_benchmarking::v2;
+use frame_support::traits::EstimateNextSessionRotation;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+// Nominate the maximum count of the collators on behalf of the pool, so that the most backings
+// need to be updated.
+fn nominate_max<T>()
+where
+	T: Config,
+{
+	let cs = (0..T::MaxNominations::get())
+		.map(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<darwinia_staking::Collators<T>>::insert(&c, Perbill::zero());
+
+			c
+		})
+		.collect();
+
+	<Pallet<T>>::nominate(RawOrigin::Root.into(), cs, Vec::new()).unwrap();
+}
+
+// The amount which could be both staked and redeemed.
+fn min_amount<T>() -> Balance
+where
+	T: Config,
+{
+	T::MinStakingAmount::get().max(T::MinRedeemingAmount::get())
+}
+
+// Redeem in `count` different sessions, so that the redemptions can't be merged.
+fn redeem_in_sessions<T>(who: &T::AccountId, count: u32)
+where
+	T: Config,
+{
+	let amount = min_amount::<T>();
+
+	(0..count).for_each(|_| {
+		<Pallet<T>>::stake(RawOrigin::Signed(who.clone()).into(), amount).unwrap();
+		<Pallet<T>>::redeem(RawOrigin::Signed(who.clone()).into(), amount).unwrap();
+		<frame_system::Pallet<T>>::set_block_number(
+			<frame_system::Pallet<T>>::block_number()
+				+ <T as darwinia_staking::Config>::NextSessionRotation::average_session_length()
+					.max(1_u32.into()),
+		);
+	});
+}
+
+#[v2::benchmarks]
+mod benchmarks {
+	// darwinia
+	use super::*;
+
+	#[benchmark]
+	fn stake() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let amount = min_amount::<T>();
+
+		T::RingCurrency::make_free_balance_be(&a, 2 * amount);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), amount).unwrap();
+		nominate_max::<T>();
+
+		// The worst case:
+		//
+		// Convert the RING at the exchange rate of an existing pool, which nominates the maximum
+		// count of the collators.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a), amount);
+
+		assert_eq!(<TotalShares<T>>::get(), 2 * amount);
+	}
+
+	#[benchmark]
+	fn redeem() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let amount = min_amount::<T>();
+		let count = 16;
+
+		T::RingCurrency::make_free_balance_be(&a, (count as Balance + 2) * amount);
+		redeem_in_sessions::<T>(&a, count);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), 2 * amount).unwrap();
+		nominate_max::<T>();
+		<frame_system::Pallet<T>>::set_block_number(<darwinia_staking::Pallet<T>>::claimable_at(
+			<frame_system::Pallet<T>>::block_number(),
+		));
+
+		let _ = T::RingCurrency::deposit_creating(&account_id(), amount);
+
+		// The worst case:
+		//
+		// Claim the maximum count of the pool's matured unstakings and stake the idle RING before
+		// unstaking.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a), amount);
+
+		assert_eq!(<TotalShares<T>>::get(), amount);
+		assert_eq!(<RedemptionPools<T>>::iter().count() as u32, count + 1);
+	}
+
+	#[benchmark]
+	fn claim() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let count = 16;
+
+		T::RingCurrency::make_free_balance_be(&a, count as Balance * min_amount::<T>());
+
+		// The worst case:
+		//
+		// Claim all the redemptions which mature in different sessions.
+		redeem_in_sessions::<T>(&a, count);
+		<frame_system::Pallet<T>>::set_block_number(<darwinia_staking::Pallet<T>>::claimable_at(
+			<frame_system::Pallet<T>>::block_number(),
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()));
+
+		assert!(<Redemptions<T>>::iter_prefix(&a).next().is_none());
+		assert_eq!(<ClaimableRing<T>>::get(), 0);
+	}
+
+	#[benchmark]
+	fn nominate(x: Linear<1, 16>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let amount = T::MinStakingAmount::get();
+		let x = x.min(T::MaxNominations::get());
+		let cs = (0..x)
+			.map(|i| {
+				let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+				<darwinia_staking::Collators<T>>::insert(&c, Perbill::zero());
+
+				c
+			})
+			.collect::<Vec<_>>();
+
+		T::RingCurrency::make_free_balance_be(&a, amount);
+		<Pallet<T>>::stake(RawOrigin::Signed(a).into(), amount).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, cs, Vec::new());
+
+		assert_eq!(
+			<darwinia_staking::Pallet<T>>::nominator_of(account_id::<T::AccountId>())
+				.map(|n| n.len() as u32),
+			Some(x)
+		);
+	}
+}
@@ -0,0 +1,468 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Darwinia liquid staking pallet
+//!
+//! ## Overview
+//!
+//! Stake RING through a pool-operated ledger in the [`darwinia_staking`] and receive the liquid
+//! staking token, the [`Config::Lst`], in exchange.
+//! The token is a plain asset, so it could be used in the EVM while the RING behind it keeps
+//! earning the staking rewards.
+//!
+//! ### Exchange rate:
+//! The pool's rewards are compounded into its ledger, so each share is backed by more RING as the
+//! sessions go by, or by less if the pool gets slashed.
+//! The rewards which are paid out but not staked yet, e.g. the ones which failed to be staked, are
+//! counted as well and get staked at the next [`Call::stake`] or [`Call::redeem`].
+//! The exchange rate is the pool's RING, see [`Pallet::pooled_ring`], divided by the
+//! [`TotalShares`].
+//!
+//! ### Redemption:
+//! Redeeming burns the shares and unstakes the equivalent RING from the pool's ledger.
+//! The RING could be claimed through the [`Call::claim`] after the
+//! [`darwinia_staking::Config::MinStakingDuration`].
+//!
+//! The redemptions which mature in the same session share the pool's unstaking of that session,
+//! see [`darwinia_staking::Pallet::claimable_at`] and [`RedemptionPools`].
+//! So, the pool keeps at most one unstaking per session, no matter how many redemptions there are.
+//! If the unstaking gets slashed, each of them loses the same ratio, no matter who claims first.
+//!
+//! Redeem at least for the [`Config::MinRedeemingAmount`] of RING, to avoid the dust
+//! redemptions.
+//!
+//! ### Availability:
+//! The pallet is only deployed to the testnets, Pangolin and Pangoro, for now.
+//!
+//! ### Operation:
+//! The [`Config::OperatorOrigin`] decides which collators the pool nominates.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod weights;
+pub use weights::WeightInfo;
+
+// crates.io
+use codec::FullCodec;
+// darwinia
+use darwinia_deposit::SimpleAsset;
+use darwinia_staking::RewardDestination;
+use dc_types::Balance;
+// substrate
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		Currency,
+		ExistenceRequirement::{AllowDeath, KeepAlive},
+	},
+	PalletId,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use sp_runtime::{helpers_128bit, traits::AccountIdConversion, ArithmeticError, Perbill, Rounding};
+use sp_std::prelude::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	// darwinia
+	use crate::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + darwinia_staking::Config {
+		/// Override the [`frame_system::Config::RuntimeEvent`].
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Liquid staking token.
+		type Lst: SimpleAsset<AccountId = Self::AccountId>;
+
+		/// Origin for operating the pool's nominations.
+		type OperatorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Minimum amount to stake at least.
+		#[pallet::constant]
+		type MinStakingAmount: Get<Balance>;
+
+		/// Minimum amount of RING to redeem at least.
+		#[pallet::constant]
+		type MinRedeemingAmount: Get<Balance>;
+	}
+
+	#[allow(missing_docs)]
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Some RING has been staked into the pool.
+		Staked { staker: T::AccountId, ring_amount: Balance, shares: Balance },
+		/// Some shares have been redeemed.
+		///
+		/// The RING will be claimable at the given block, the end of the session in which it
+		/// matures.
+		Redeemed {
+			staker: T::AccountId,
+			shares: Balance,
+			ring_amount: Balance,
+			claimable_at: T::BlockNumber,
+		},
+		/// The redeemed RING has been claimed.
+		Claimed { staker: T::AccountId, ring_amount: Balance },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Stake at least for a specific amount.
+		StakeAtLeastSome,
+		/// Redeem at least for a specific amount.
+		RedeemAtLeastSome,
+		/// The RING is too little to be exchanged for any share.
+		ZeroShares,
+	}
+
+	/// The total shares of the pool.
+	///
+	/// It equals the issuance of the [`Config::Lst`].
+	#[pallet::storage]
+	#[pallet::getter(fn total_shares)]
+	pub type TotalShares<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The RING in the pool's free balance which belongs to the matured redemptions.
+	#[pallet::storage]
+	#[pallet::getter(fn claimable_ring)]
+	pub type ClaimableRing<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The redemptions which mature in the same session, keyed by the end of that session.
+	#[pallet::storage]
+	#[pallet::getter(fn redemption_pool_of)]
+	pub type RedemptionPools<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, RedemptionPool>;
+
+	/// The redeemed RING which is waiting to be claimed, keyed by the block it becomes claimable
+	/// at.
+	#[pallet::storage]
+	#[pallet::getter(fn redemption_of)]
+	pub type Redemptions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::BlockNumber,
+		Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(PhantomData<T>);
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Stake RING into the pool for the shares.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::stake())]
+		pub fn stake(origin: OriginFor<T>, ring_amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if ring_amount == 0 || ring_amount < T::MinStakingAmount::get() {
+				Err(<Error<T>>::StakeAtLeastSome)?;
+			}
+
+			let shares = Self::ring2shares(ring_amount).ok_or(ArithmeticError::Overflow)?;
+
+			if shares == 0 {
+				Err(<Error<T>>::ZeroShares)?;
+			}
+
+			T::RingCurrency::transfer(&who, &account_id(), ring_amount, KeepAlive)?;
+			// Stake the new RING together with the idle one.
+			Self::compound()?;
+
+			T::Lst::mint(&who, shares)?;
+			<TotalShares<T>>::mutate(|s| *s += shares);
+
+			Self::deposit_event(Event::Staked { staker: who, ring_amount, shares });
+
+			Ok(())
+		}
+
+		/// Burn the shares and unstake the equivalent RING from the pool.
+		///
+		/// The equivalent RING must be at least the [`Config::MinRedeemingAmount`].
+		///
+		/// The RING could be claimed through the [`Call::claim`] after the
+		/// [`darwinia_staking::Config::MinStakingDuration`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem())]
+		pub fn redeem(origin: OriginFor<T>, shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let ring_amount = Self::shares2ring(shares).ok_or(ArithmeticError::Overflow)?;
+
+			if ring_amount == 0 || ring_amount < T::MinRedeemingAmount::get() {
+				Err(<Error<T>>::RedeemAtLeastSome)?;
+			}
+
+			T::Lst::burn(&who, shares)?;
+			<TotalShares<T>>::mutate(|s| *s = s.saturating_sub(shares));
+			// Release the matured unstakings.
+			Self::settle()?;
+			// The idle RING is counted in the exchange rate, so it must be unstakable.
+			Self::compound()?;
+			<darwinia_staking::Pallet<T>>::unstake(
+				RawOrigin::Signed(account_id()).into(),
+				ring_amount,
+				0,
+				Vec::new(),
+			)?;
+
+			// Same as the pool's unstaking, so the redemptions of the same session share it.
+			let claimable_at = <darwinia_staking::Pallet<T>>::claimable_at(
+				<frame_system::Pallet<T>>::block_number(),
+			);
+
+			<RedemptionPools<T>>::mutate(claimable_at, |p| {
+				let p = p.get_or_insert_with(Default::default);
+
+				p.redeemed += ring_amount;
+				p.ring += ring_amount;
+			});
+			<Redemptions<T>>::mutate(&who, claimable_at, |r| *r += ring_amount);
+
+			Self::deposit_event(Event::Redeemed { staker: who, shares, ring_amount, claimable_at });
+
+			Ok(())
+		}
+
+		/// Claim the redeemed RING which has become claimable.
+		///
+		/// If the pool's unstaking has been slashed, the redeemed RING will be reduced by the same
+		/// ratio.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			let redemptions =
+				<Redemptions<T>>::iter_prefix(&who).filter(|(t, _)| t <= &now).collect::<Vec<_>>();
+
+			if redemptions.is_empty() {
+				return Ok(());
+			}
+
+			Self::settle()?;
+
+			let mut ring_amount = 0;
+			let mut released = 0;
+
+			for (t, r) in redemptions {
+				<Redemptions<T>>::remove(&who, t);
+				<RedemptionPools<T>>::mutate_exists(t, |p| {
+					let Some(rp) = p else {
+						return;
+					};
+					let paid = helpers_128bit::multiply_by_rational_with_rounding(
+						r,
+						rp.ring,
+						rp.redeemed,
+						Rounding::Down,
+					)
+					.unwrap_or_default()
+					.min(rp.ring);
+
+					rp.redeemed = rp.redeemed.saturating_sub(r);
+					rp.ring -= paid;
+					ring_amount += paid;
+					released += paid;
+
+					if rp.redeemed == 0 {
+						// The rounding dust goes back to the pool.
+						released += rp.ring;
+						*p = None;
+					}
+				});
+			}
+
+			<ClaimableRing<T>>::mutate(|r| *r = r.saturating_sub(released));
+
+			if ring_amount == 0 {
+				return Ok(());
+			}
+
+			T::RingCurrency::transfer(&account_id(), &who, ring_amount, AllowDeath)?;
+
+			Self::deposit_event(Event::Claimed { staker: who, ring_amount });
+
+			Ok(())
+		}
+
+		/// Nominate the collators on behalf of the pool.
+		///
+		/// See [`darwinia_staking::Call::nominate_multiple`].
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::nominate(targets.len() as _))]
+		pub fn nominate(
+			origin: OriginFor<T>,
+			targets: Vec<T::AccountId>,
+			weights: Vec<Perbill>,
+		) -> DispatchResult {
+			T::OperatorOrigin::ensure_origin(origin)?;
+
+			<darwinia_staking::Pallet<T>>::nominate_multiple(
+				RawOrigin::Signed(account_id()).into(),
+				targets,
+				weights,
+			)
+		}
+	}
+}
+pub use pallet::*;
+
+impl<T> Pallet<T>
+where
+	T: Config,
+{
+	/// The RING of the pool, which is shared by the share holders.
+	///
+	/// Including the staked RING and the idle RING, see [`Pallet::idle_ring`].
+	pub fn pooled_ring() -> Balance {
+		<darwinia_staking::Ledgers<T>>::get(account_id::<T::AccountId>())
+			.map(|l| l.staked_ring)
+			.unwrap_or_default()
+			.saturating_add(Self::idle_ring())
+	}
+
+	/// The RING in the pool's free balance, which hasn't been staked yet.
+	///
+	/// E.g. the rewards which failed to be staked.
+	/// The [`ClaimableRing`] and the existential deposit are excluded.
+	pub fn idle_ring() -> Balance {
+		T::RingCurrency::free_balance(&account_id())
+			.saturating_sub(<ClaimableRing<T>>::get())
+			.saturating_sub(T::RingCurrency::minimum_balance())
+	}
+
+	// Stake the idle RING into the pool's ledger.
+	fn compound() -> DispatchResult {
+		let pool = account_id::<T::AccountId>();
+		let ring_amount = Self::idle_ring();
+
+		if ring_amount != 0 {
+			<darwinia_staking::Pallet<T>>::stake(
+				RawOrigin::Signed(pool.clone()).into(),
+				ring_amount,
+				0,
+				Vec::new(),
+			)?;
+
+			// Compound the rewards, so that they are shared by all the share holders.
+			if <darwinia_staking::Payees<T>>::get(&pool) != RewardDestination::Staked {
+				<darwinia_staking::Payees<T>>::insert(&pool, RewardDestination::Staked);
+			}
+		}
+
+		Ok(())
+	}
+
+	// Claim the pool's matured unstakings.
+	//
+	// Record the RING which is left after the slashes, for the redemptions which mature in the
+	// same session.
+	fn settle() -> DispatchResult {
+		let pool = account_id::<T::AccountId>();
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut claimable = 0;
+		let mut matured = false;
+
+		for (t, u) in
+			<darwinia_staking::Unstakings<T>>::iter_prefix(&pool).filter(|(t, _)| t <= &now)
+		{
+			<RedemptionPools<T>>::mutate(t, |p| {
+				if let Some(p) = p {
+					p.ring = u.ring;
+					claimable += u.ring;
+				}
+			});
+
+			matured = true;
+		}
+
+		if matured {
+			<darwinia_staking::Pallet<T>>::claim(RawOrigin::Signed(pool).into())?;
+			<ClaimableRing<T>>::mutate(|r| *r += claimable);
+		}
+
+		Ok(())
+	}
+
+	/// Convert the RING to the shares at the current exchange rate.
+	pub fn ring2shares(ring_amount: Balance) -> Option<Balance> {
+		let total_shares = <TotalShares<T>>::get();
+		let pooled_ring = Self::pooled_ring();
+
+		if total_shares == 0 || pooled_ring == 0 {
+			return Some(ring_amount);
+		}
+
+		helpers_128bit::multiply_by_rational_with_rounding(
+			ring_amount,
+			total_shares,
+			pooled_ring,
+			Rounding::Down,
+		)
+	}
+
+	/// Convert the shares to the RING at the current exchange rate.
+	pub fn shares2ring(shares: Balance) -> Option<Balance> {
+		let total_shares = <TotalShares<T>>::get();
+
+		if total_shares == 0 {
+			return Some(0);
+		}
+
+		helpers_128bit::multiply_by_rational_with_rounding(
+			shares.min(total_shares),
+			Self::pooled_ring(),
+			total_shares,
+			Rounding::Down,
+		)
+	}
+}
+
+/// The redemptions which mature at the same block.
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct RedemptionPool {
+	/// The RING which was promised to the redeemers at the redemptions.
+	pub redeemed: Balance,
+	/// The RING which is left for the redeemers, after the slashes.
+	pub ring: Balance,
+}
+
+/// The account of the liquid staking pool.
+pub fn account_id<A>() -> A
+where
+	A: FullCodec,
+{
+	PalletId(*b"dar/lstk").into_account_truncating()
+}
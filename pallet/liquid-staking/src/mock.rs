@@ -0,0 +1,311 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+pub use crate as darwinia_liquid_staking;
+pub use dc_types::{AssetId, Balance, Moment, UNIT};
+
+// substrate
+use frame_support::traits::GenesisBuild;
+use sp_io::TestExternalities;
+
+impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = u32;
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockHashCount = ();
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = sp_core::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type Header = sp_runtime::testing::Header;
+	type Index = u64;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type MinimumPeriod = ();
+	type Moment = Moment;
+	type OnTimestampSet = ();
+	type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = frame_support::traits::ConstU128<0>;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Runtime {
+	type ApprovalDeposit = ();
+	type AssetAccountDeposit = ();
+	type AssetDeposit = ();
+	type AssetId = AssetId;
+	type AssetIdParameter = codec::Compact<AssetId>;
+	type Balance = Balance;
+	type CallbackHandle = ();
+	type CreateOrigin = frame_support::traits::AsEnsureOriginWithArg<
+		frame_system::EnsureSignedBy<frame_support::traits::IsInVec<()>, u32>,
+	>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = frame_system::EnsureRoot<u32>;
+	type Freezer = ();
+	type MetadataDepositBase = ();
+	type MetadataDepositPerByte = ();
+	type RemoveItemsLimit = ();
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = frame_support::traits::ConstU32<4>;
+	type WeightInfo = ();
+}
+
+pub struct MockAsset<const A: AssetId>;
+impl<const A: AssetId> darwinia_deposit::SimpleAsset for MockAsset<A> {
+	type AccountId = u32;
+
+	fn mint(beneficiary: &Self::AccountId, amount: Balance) -> sp_runtime::DispatchResult {
+		Assets::mint(RuntimeOrigin::signed(0), A.into(), *beneficiary, amount)
+	}
+
+	fn burn(who: &Self::AccountId, amount: Balance) -> sp_runtime::DispatchResult {
+		if Assets::balance(A, who) < amount {
+			Err(<pallet_assets::Error<Runtime>>::BalanceLow)?;
+		}
+
+		Assets::burn(RuntimeOrigin::signed(0), A.into(), *who, amount)
+	}
+}
+impl darwinia_deposit::Config for Runtime {
+	type DepositInterest = ();
+	type Kton = MockAsset<0>;
+	type LinearVesting = frame_support::traits::ConstBool<false>;
+	type MaxDeposits = frame_support::traits::ConstU32<16>;
+	type MaxLockingMonths = frame_support::traits::ConstU8<36>;
+	type MinLockingAmount = frame_support::traits::ConstU128<UNIT>;
+	type MinLockingMonths = frame_support::traits::ConstU8<1>;
+	type PenaltyMultiplier = frame_support::traits::ConstU32<3>;
+	type Ring = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type Staking = Staking;
+	type WeightInfo = ();
+}
+
+pub enum RingStaking {}
+impl darwinia_staking::Stake for RingStaking {
+	type AccountId = u32;
+	type Item = Balance;
+
+	fn stake(who: &Self::AccountId, item: Self::Item) -> sp_runtime::DispatchResult {
+		<Balances as frame_support::traits::Currency<_>>::transfer(
+			who,
+			&darwinia_staking::account_id(),
+			item,
+			frame_support::traits::ExistenceRequirement::KeepAlive,
+		)
+	}
+
+	fn unstake(who: &Self::AccountId, item: Self::Item) -> sp_runtime::DispatchResult {
+		<Balances as frame_support::traits::Currency<_>>::transfer(
+			&darwinia_staking::account_id(),
+			who,
+			item,
+			frame_support::traits::ExistenceRequirement::AllowDeath,
+		)
+	}
+}
+impl darwinia_staking::Slash for RingStaking {
+	fn slash(
+		_: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let amount = fraction * item;
+		let (_, unslashed) = <Balances as frame_support::traits::Currency<_>>::slash(
+			&darwinia_staking::account_id(),
+			amount,
+		);
+
+		Ok(amount - unslashed)
+	}
+}
+pub enum KtonStaking {}
+impl darwinia_staking::Stake for KtonStaking {
+	type AccountId = u32;
+	type Item = Balance;
+
+	fn stake(who: &Self::AccountId, item: Self::Item) -> sp_runtime::DispatchResult {
+		Assets::transfer(
+			RuntimeOrigin::signed(*who),
+			0.into(),
+			darwinia_staking::account_id(),
+			item,
+		)
+	}
+
+	fn unstake(who: &Self::AccountId, item: Self::Item) -> sp_runtime::DispatchResult {
+		Assets::transfer(
+			RuntimeOrigin::signed(darwinia_staking::account_id()),
+			0.into(),
+			*who,
+			item,
+		)
+	}
+}
+impl darwinia_staking::Slash for KtonStaking {
+	fn slash(
+		_: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let amount = fraction * item;
+
+		Assets::burn(RuntimeOrigin::signed(0), 0.into(), darwinia_staking::account_id(), amount)?;
+
+		Ok(amount)
+	}
+}
+frame_support::parameter_types! {
+	pub const PayoutFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(40);
+	pub const MaxSlashFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	pub static MergeRedemptionsBySession: bool = false;
+}
+// Same as `()` unless the static is changed, then the sessions last for 10 blocks.
+pub enum RedemptionSessionRotation {}
+impl frame_support::traits::EstimateNextSessionRotation<u64> for RedemptionSessionRotation {
+	fn average_session_length() -> u64 {
+		10
+	}
+
+	fn estimate_current_session_progress(
+		_: u64,
+	) -> (Option<sp_runtime::Permill>, frame_support::weights::Weight) {
+		(None, Default::default())
+	}
+
+	fn estimate_next_session_rotation(now: u64) -> (Option<u64>, frame_support::weights::Weight) {
+		(MergeRedemptionsBySession::get().then_some((now / 10 + 1) * 10), Default::default())
+	}
+}
+impl darwinia_staking::Config for Runtime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CommissionChangeDelay = frame_support::traits::ConstU32<2>;
+	type Deposit = Deposit;
+	type HistoryDepth = frame_support::traits::ConstU32<3>;
+	type InactivitySlashFraction = ();
+	type KeysRegistry = frame_support::traits::Everything;
+	type Kton = KtonStaking;
+	type ManagerOrigin = frame_system::EnsureRoot<u32>;
+	type MaxCommission = ();
+	type MaxCommissionDelta = ();
+	type MaxDeposits = frame_support::traits::ConstU32<16>;
+	type MaxInactiveSessions = frame_support::traits::ConstU32<2>;
+	type MaxNominations = frame_support::traits::ConstU32<16>;
+	type MaxNominatorsPerCollator = frame_support::traits::ConstU32<3>;
+	type MaxSlashFraction = MaxSlashFraction;
+	type MinAuthoredBlocks = frame_support::traits::ConstU32<0>;
+	type MinCollatorStake = ();
+	type MinStakingDuration = frame_support::traits::ConstU64<3>;
+	type NextSessionRotation = RedemptionSessionRotation;
+	type PayoutFraction = PayoutFraction;
+	type PowerCalculator = ();
+	type RewardRemainder = ();
+	type Ring = RingStaking;
+	type RingCurrency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type SlashCancelOrigin = frame_system::EnsureRoot<u32>;
+	type SlashDeferDuration = frame_support::traits::ConstU32<1>;
+	type UnixTime = Timestamp;
+	type WeightInfo = ();
+}
+
+impl darwinia_liquid_staking::Config for Runtime {
+	type Lst = MockAsset<1>;
+	type MinRedeemingAmount = frame_support::traits::ConstU128<UNIT>;
+	type MinStakingAmount = frame_support::traits::ConstU128<UNIT>;
+	type OperatorOrigin = frame_system::EnsureRoot<u32>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+frame_support::construct_runtime! {
+	pub enum Runtime where
+		Block = frame_system::mocking::MockBlock<Runtime>,
+		NodeBlock = frame_system::mocking::MockBlock<Runtime>,
+		UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>,
+	{
+		System: frame_system,
+		Timestamp: pallet_timestamp,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		Deposit: darwinia_deposit,
+		Staking: darwinia_staking,
+		LiquidStaking: darwinia_liquid_staking,
+	}
+}
+
+pub fn efflux(number: u64) {
+	System::set_block_number(System::block_number() + number);
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: (1..=3).map(|i| (i, 1_000 * UNIT)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_assets::GenesisConfig::<Runtime> {
+		assets: vec![(0, 0, true, 1), (1, 0, true, 1)],
+		metadata: vec![
+			(0, b"KTON".to_vec(), b"KTON".to_vec(), 18),
+			(1, b"LRNG".to_vec(), b"LRNG".to_vec(), 18),
+		],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	darwinia_staking::GenesisConfig::<Runtime> { collator_count: 1, ..Default::default() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext = TestExternalities::from(storage);
+
+	ext.execute_with(|| System::set_block_number(1));
+
+	ext
+}
@@ -0,0 +1,273 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::{mock::*, *};
+// substrate
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+use sp_staking::offence::{DisableStrategy, OffenceDetails, OnOffenceHandler};
+
+fn pool() -> u32 {
+	darwinia_liquid_staking::account_id()
+}
+
+#[test]
+fn stake_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidStaking::stake(RuntimeOrigin::signed(1), 0),
+			<Error<Runtime>>::StakeAtLeastSome
+		);
+		assert_noop!(
+			LiquidStaking::stake(RuntimeOrigin::signed(1), UNIT - 1),
+			<Error<Runtime>>::StakeAtLeastSome
+		);
+
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_eq!(Balances::free_balance(1), 900 * UNIT);
+		assert_eq!(Assets::balance(1, 1), 100 * UNIT);
+		assert_eq!(LiquidStaking::total_shares(), 100 * UNIT);
+		assert_eq!(Staking::ledger_of(pool()).unwrap().staked_ring, 100 * UNIT);
+		assert_eq!(Staking::payee_of(pool()), darwinia_staking::RewardDestination::Staked);
+
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 50 * UNIT));
+		assert_eq!(Assets::balance(1, 2), 50 * UNIT);
+		assert_eq!(LiquidStaking::total_shares(), 150 * UNIT);
+		assert_eq!(LiquidStaking::pooled_ring(), 150 * UNIT);
+	});
+}
+
+#[test]
+fn stake_should_reject_zero_shares() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+
+		// Each share is backed by too much RING.
+		<TotalShares<Runtime>>::put(1);
+		assert_eq!(LiquidStaking::ring2shares(UNIT), Some(0));
+		assert_noop!(
+			LiquidStaking::stake(RuntimeOrigin::signed(2), UNIT),
+			<Error<Runtime>>::ZeroShares
+		);
+	});
+}
+
+#[test]
+fn exchange_rate_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(3), 100 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(3), Default::default()));
+		assert_ok!(LiquidStaking::nominate(RuntimeOrigin::root(), vec![3], Vec::new()));
+		Staking::elect(0);
+		Staking::reward_by_ids(&[(3, 20)]);
+		Staking::payout(0, 6 * 60 * 60 * 1_000, 0);
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1), 3, 0));
+
+		// The reward has been compounded into the pool.
+		let pooled_ring = LiquidStaking::pooled_ring();
+
+		assert!(pooled_ring > 100 * UNIT);
+		assert_eq!(LiquidStaking::shares2ring(100 * UNIT), Some(pooled_ring));
+
+		let shares = LiquidStaking::ring2shares(100 * UNIT).unwrap();
+
+		assert!(shares < 100 * UNIT);
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 100 * UNIT));
+		assert_eq!(Assets::balance(1, 2), shares);
+	});
+}
+
+#[test]
+fn idle_ring_should_be_shared() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+
+		// Some rewards which failed to be staked.
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(3), pool(), 10 * UNIT));
+		assert_eq!(LiquidStaking::idle_ring(), 10 * UNIT);
+		assert_eq!(LiquidStaking::pooled_ring(), 110 * UNIT);
+		assert_eq!(LiquidStaking::shares2ring(100 * UNIT), Some(110 * UNIT));
+
+		// The idle RING has been staked along with the new stakes.
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 110 * UNIT));
+		assert_eq!(Assets::balance(1, 2), 100 * UNIT);
+		assert_eq!(LiquidStaking::idle_ring(), 0);
+		assert_eq!(Staking::ledger_of(pool()).unwrap().staked_ring, 220 * UNIT);
+	});
+}
+
+#[test]
+fn redeem_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(1), 100 * UNIT + 1),
+			<pallet_assets::Error<Runtime>>::BalanceLow
+		);
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(1), 0),
+			<Error<Runtime>>::RedeemAtLeastSome
+		);
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(1), UNIT - 1),
+			<Error<Runtime>>::RedeemAtLeastSome
+		);
+
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(1), 40 * UNIT));
+		assert_eq!(Assets::balance(1, 1), 60 * UNIT);
+		assert_eq!(LiquidStaking::total_shares(), 60 * UNIT);
+		assert_eq!(LiquidStaking::pooled_ring(), 60 * UNIT);
+		assert_eq!(LiquidStaking::redemption_of(1, 4), 40 * UNIT);
+		assert_eq!(Staking::unstaking_of(pool(), 4).unwrap().ring, 40 * UNIT);
+	});
+}
+
+#[test]
+fn redemptions_should_be_merged_by_session() {
+	new_test_ext().execute_with(|| {
+		MergeRedemptionsBySession::set(true);
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 100 * UNIT));
+
+		// Many redemptions of the same session share a single unstaking of the pool.
+		(0..20).for_each(|_| {
+			assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(1), UNIT));
+		});
+		efflux(2);
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(2), 10 * UNIT));
+		assert_eq!(Staking::unstaking_of(pool(), 10).unwrap().ring, 30 * UNIT);
+		assert_eq!(<darwinia_staking::Unstakings<Runtime>>::iter_prefix(pool()).count(), 1);
+		assert_eq!(
+			LiquidStaking::redemption_pool_of(10),
+			Some(RedemptionPool { redeemed: 30 * UNIT, ring: 30 * UNIT })
+		);
+		assert_eq!(LiquidStaking::redemption_of(1, 10), 20 * UNIT);
+		assert_eq!(LiquidStaking::redemption_of(2, 10), 10 * UNIT);
+
+		// The next session's redemptions take another one.
+		efflux(4);
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(2), 10 * UNIT));
+		assert_eq!(Staking::unstaking_of(pool(), 20).unwrap().ring, 10 * UNIT);
+
+		System::set_block_number(10);
+		assert_ok!(LiquidStaking::claim(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 920 * UNIT);
+		assert_ok!(LiquidStaking::claim(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::free_balance(2), 910 * UNIT);
+		assert!(LiquidStaking::redemption_pool_of(10).is_none());
+	});
+}
+
+#[test]
+fn claim_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 100 * UNIT));
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(1), 40 * UNIT));
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(2), 100 * UNIT));
+
+		assert_ok!(LiquidStaking::claim(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 900 * UNIT);
+
+		efflux(3);
+		assert_ok!(LiquidStaking::claim(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 940 * UNIT);
+		assert_eq!(LiquidStaking::redemption_of(1, 4), 0);
+
+		// The pool's unstakings have been claimed by the previous claimer.
+		assert!(Staking::unstaking_of(pool(), 4).is_none());
+		assert_ok!(LiquidStaking::claim(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::free_balance(2), 1_000 * UNIT);
+		assert_eq!(Balances::free_balance(pool()), 0);
+		assert_eq!(LiquidStaking::claimable_ring(), 0);
+		assert!(LiquidStaking::redemption_pool_of(4).is_none());
+	});
+}
+
+#[test]
+fn claim_should_share_the_slash() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(2), 100 * UNIT));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(3), 100 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(3), Default::default()));
+		assert_ok!(LiquidStaking::nominate(RuntimeOrigin::root(), vec![3], Vec::new()));
+		assert_eq!(Staking::elect(0), vec![3]);
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(1), 50 * UNIT));
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(2), 50 * UNIT));
+		assert_eq!(
+			LiquidStaking::redemption_pool_of(4),
+			Some(RedemptionPool { redeemed: 100 * UNIT, ring: 100 * UNIT })
+		);
+
+		<Staking as OnOffenceHandler<_, _, _>>::on_offence(
+			&[OffenceDetails { offender: 3, reporters: Vec::new() }],
+			&[Perbill::from_percent(10)],
+			0,
+			DisableStrategy::Never,
+		);
+		Staking::apply_unapplied_slashes(1);
+		assert_eq!(Staking::unstaking_of(pool(), 4).unwrap().ring, 90 * UNIT);
+		assert_eq!(LiquidStaking::pooled_ring(), 90 * UNIT);
+
+		// The first claimer doesn't get more than the others.
+		efflux(3);
+		assert_ok!(LiquidStaking::claim(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 945 * UNIT);
+		assert_eq!(LiquidStaking::claimable_ring(), 45 * UNIT);
+		assert_ok!(LiquidStaking::claim(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::free_balance(2), 945 * UNIT);
+		assert_eq!(LiquidStaking::claimable_ring(), 0);
+		assert!(LiquidStaking::redemption_pool_of(4).is_none());
+	});
+}
+
+#[test]
+fn redeem_should_claim_the_matured_unstakings() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(1), 40 * UNIT));
+
+		efflux(3);
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(1), 10 * UNIT));
+		assert!(Staking::unstaking_of(pool(), 4).is_none());
+		assert_eq!(Staking::unstaking_of(pool(), 7).unwrap().ring, 10 * UNIT);
+		assert_eq!(LiquidStaking::claimable_ring(), 40 * UNIT);
+		// The claimable RING isn't counted in the exchange rate.
+		assert_eq!(LiquidStaking::pooled_ring(), 50 * UNIT);
+		assert_eq!(LiquidStaking::shares2ring(50 * UNIT), Some(50 * UNIT));
+	});
+}
+
+#[test]
+fn nominate_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::stake(RuntimeOrigin::signed(1), 100 * UNIT));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(3), 100 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(3), Default::default()));
+		assert_noop!(
+			LiquidStaking::nominate(RuntimeOrigin::signed(1), vec![3], Vec::new()),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(LiquidStaking::nominate(RuntimeOrigin::root(), vec![3], Vec::new()));
+		assert_eq!(Staking::total_backing_of(3).ring, 100 * UNIT);
+	});
+}
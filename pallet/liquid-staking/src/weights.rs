@@ -0,0 +1,317 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_liquid_staking`.
+//!
+//! THESE WEIGHTS ARE NOT GENERATED BY THE SUBSTRATE BENCHMARK CLI.
+//! They are hand-written placeholders, estimated from the storage accesses of the benchmarks.
+//! Regenerate this file with the command below before going to production.
+
+// Regenerate with:
+// target/release/darwinia
+// benchmark
+// pallet
+// --header
+// .maintain/license-header
+// --template
+// .maintain/pallet-weight-template.hbs
+// --execution
+// wasm
+// --heap-pages
+// 4096
+// --chain
+// pangolin-local
+// --output
+// pallet/liquid-staking/src/weights.rs
+// --extrinsic
+// *
+// --pallet
+// darwinia-liquid-staking
+// --steps
+// 50
+// --repeat
+// 20
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(missing_docs)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for darwinia_liquid_staking.
+pub trait WeightInfo {
+	fn stake() -> Weight;
+	fn redeem() -> Weight;
+	fn claim() -> Weight;
+	fn nominate(x: u32, ) -> Weight;
+}
+
+/// Weights for darwinia_liquid_staking using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: LiquidStaking TotalShares (r:1 w:1)
+	/// Proof: LiquidStaking TotalShares (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:1 w:0)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking ClaimableRing (r:1 w:0)
+	/// Proof: LiquidStaking ClaimableRing (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2410`
+		//  Estimated: `104080`
+		// Minimum execution time: 154_756 nanoseconds.
+		Weight::from_ref_time(171_214_418)
+			.saturating_add(Weight::from_proof_size(104080))
+			.saturating_add(T::DbWeight::get().reads(43_u64))
+			.saturating_add(T::DbWeight::get().writes(40_u64))
+	}
+	/// Storage: LiquidStaking TotalShares (r:1 w:1)
+	/// Proof: LiquidStaking TotalShares (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:17)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking Redemptions (r:1 w:1)
+	/// Proof: LiquidStaking Redemptions (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking ClaimableRing (r:1 w:1)
+	/// Proof: LiquidStaking ClaimableRing (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking RedemptionPools (r:17 w:17)
+	/// Proof: LiquidStaking RedemptionPools (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:1 w:0)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2562`
+		//  Estimated: `190847`
+		// Minimum execution time: 148_476 nanoseconds.
+		Weight::from_ref_time(253_387_532)
+			.saturating_add(Weight::from_proof_size(190847))
+			.saturating_add(T::DbWeight::get().reads(77_u64))
+			.saturating_add(T::DbWeight::get().writes(75_u64))
+	}
+	/// Storage: LiquidStaking Redemptions (r:17 w:16)
+	/// Proof: LiquidStaking Redemptions (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking ClaimableRing (r:1 w:1)
+	/// Proof: LiquidStaking ClaimableRing (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking RedemptionPools (r:16 w:16)
+	/// Proof: LiquidStaking RedemptionPools (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3871`
+		//  Estimated: `146102`
+		// Minimum execution time: 182_649 nanoseconds.
+		Weight::from_ref_time(239_532_554)
+			.saturating_add(Weight::from_proof_size(146102))
+			.saturating_add(T::DbWeight::get().reads(58_u64))
+			.saturating_add(T::DbWeight::get().writes(53_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:x+16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+16 w:x+16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn nominate(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1991 + x * (252 ±0)`
+		//  Estimated: `46472 + x * (5042 ±0)`
+		// Minimum execution time: 140_619 nanoseconds.
+		Weight::from_ref_time(152_847_718)
+			.saturating_add(Weight::from_proof_size(46472))
+			// Standard Error: 8_645
+			.saturating_add(Weight::from_ref_time(10_116_882).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(33_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5042).saturating_mul(x.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: LiquidStaking TotalShares (r:1 w:1)
+	/// Proof: LiquidStaking TotalShares (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:1 w:0)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking ClaimableRing (r:1 w:0)
+	/// Proof: LiquidStaking ClaimableRing (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2410`
+		//  Estimated: `104080`
+		// Minimum execution time: 154_756 nanoseconds.
+		Weight::from_ref_time(171_214_418)
+			.saturating_add(Weight::from_proof_size(104080))
+			.saturating_add(RocksDbWeight::get().reads(43_u64))
+			.saturating_add(RocksDbWeight::get().writes(40_u64))
+	}
+	/// Storage: LiquidStaking TotalShares (r:1 w:1)
+	/// Proof: LiquidStaking TotalShares (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:17)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking Redemptions (r:1 w:1)
+	/// Proof: LiquidStaking Redemptions (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking ClaimableRing (r:1 w:1)
+	/// Proof: LiquidStaking ClaimableRing (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking RedemptionPools (r:17 w:17)
+	/// Proof: LiquidStaking RedemptionPools (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:1 w:0)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2562`
+		//  Estimated: `190847`
+		// Minimum execution time: 148_476 nanoseconds.
+		Weight::from_ref_time(253_387_532)
+			.saturating_add(Weight::from_proof_size(190847))
+			.saturating_add(RocksDbWeight::get().reads(77_u64))
+			.saturating_add(RocksDbWeight::get().writes(75_u64))
+	}
+	/// Storage: LiquidStaking Redemptions (r:17 w:16)
+	/// Proof: LiquidStaking Redemptions (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking ClaimableRing (r:1 w:1)
+	/// Proof: LiquidStaking ClaimableRing (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: LiquidStaking RedemptionPools (r:16 w:16)
+	/// Proof: LiquidStaking RedemptionPools (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3871`
+		//  Estimated: `146102`
+		// Minimum execution time: 182_649 nanoseconds.
+		Weight::from_ref_time(239_532_554)
+			.saturating_add(Weight::from_proof_size(146102))
+			.saturating_add(RocksDbWeight::get().reads(58_u64))
+			.saturating_add(RocksDbWeight::get().writes(53_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:x+16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+16 w:x+16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn nominate(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1991 + x * (252 ±0)`
+		//  Estimated: `46472 + x * (5042 ±0)`
+		// Minimum execution time: 140_619 nanoseconds.
+		Weight::from_ref_time(152_847_718)
+			.saturating_add(Weight::from_proof_size(46472))
+			// Standard Error: 8_645
+			.saturating_add(Weight::from_ref_time(10_116_882).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(33_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5042).saturating_mul(x.into()))
+	}
+}
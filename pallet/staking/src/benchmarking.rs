@@ -0,0 +1,690 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// darwinia
+use crate::*;
+// substrate
+use frame_benchmarking::v2;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+// Nominate the maximum count of the collators, so that the most backings need to be updated.
+fn nominate_max<T>(who: &T::AccountId)
+where
+	T: Config,
+{
+	let cs = (0..T::MaxNominations::get())
+		.map(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<Collators<T>>::insert(&c, Perbill::zero());
+
+			c
+		})
+		.collect();
+
+	<Pallet<T>>::nominate_multiple(RawOrigin::Signed(who.clone()).into(), cs, Vec::new()).unwrap();
+}
+
+// Unstake in `count` different sessions, so that the unstakings can't be merged.
+//
+// One deposit will be unstaked in each session if there are enough deposits.
+fn unstake_in_sessions<T>(who: &T::AccountId, count: u32, deposits: &[DepositId<T>])
+where
+	T: Config,
+{
+	(0..count as usize).for_each(|i| {
+		<Pallet<T>>::unstake(
+			RawOrigin::Signed(who.clone()).into(),
+			UNIT,
+			UNIT,
+			deposits.get(i).map(|d| vec![*d]).unwrap_or_default(),
+		)
+		.unwrap();
+		<frame_system::Pallet<T>>::set_block_number(
+			<frame_system::Pallet<T>>::block_number()
+				+ T::NextSessionRotation::average_session_length().max(1_u32.into()),
+		);
+	});
+}
+
+// Build an exposure with `count` nominators.
+fn full_exposure<T>(count: u32) -> Exposure<T>
+where
+	T: Config,
+{
+	let ns = (0..count)
+		.map(|i| IndividualExposure {
+			who: frame_benchmarking::account("nominator", i, 0),
+			value: 1,
+		})
+		.collect();
+
+	Exposure {
+		commission: Perbill::from_percent(10),
+		total: count,
+		nominators: BoundedVec::truncate_from(ns),
+	}
+}
+
+#[v2::benchmarks]
+mod benchmarks {
+	// darwinia
+	use super::*;
+
+	#[benchmark]
+	fn stake(x: Linear<0, 16>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let ds = T::BenchmarkHelper::prepare_stakes(&a, x);
+
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+		nominate_max::<T>(&a);
+
+		// The worst case:
+		//
+		// Stake all kinds of the assets and update the backings of all the targets.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), UNIT, UNIT, ds);
+
+		assert_eq!(<Pallet<T>>::ledger_of(&a).unwrap().staked_ring, 2 * UNIT);
+	}
+
+	#[benchmark]
+	fn unstake(x: Linear<0, 16>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let ds = T::BenchmarkHelper::prepare_stakes(&a, x);
+
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), 2 * UNIT, 2 * UNIT, ds.clone())
+			.unwrap();
+		nominate_max::<T>(&a);
+
+		// The worst case:
+		//
+		// Merge into an existing unstaking which matures in the same session.
+		<Pallet<T>>::unstake(RawOrigin::Signed(a.clone()).into(), UNIT, UNIT, Vec::new()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), UNIT, UNIT, ds);
+
+		assert_eq!(
+			<Pallet<T>>::unstaking_of(
+				&a,
+				<Pallet<T>>::claimable_at(<frame_system::Pallet<T>>::block_number())
+			)
+			.unwrap()
+			.ring,
+			2 * UNIT
+		);
+	}
+
+	#[benchmark]
+	fn restake(x: Linear<0, 16>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let ds = T::BenchmarkHelper::prepare_stakes(&a, x);
+		let count = 16;
+
+		<Pallet<T>>::stake(
+			RawOrigin::Signed(a.clone()).into(),
+			count as Balance * UNIT,
+			count as Balance * UNIT,
+			ds.clone(),
+		)
+		.unwrap();
+		nominate_max::<T>(&a);
+
+		// The worst case:
+		//
+		// Cancel all the unstakings which mature in different sessions.
+		unstake_in_sessions::<T>(&a, count, &ds);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), count as Balance * UNIT, count as Balance * UNIT, ds);
+
+		assert!(<Unstakings<T>>::iter_prefix(&a).next().is_none());
+	}
+
+	#[benchmark]
+	fn claim() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let count = 16;
+		let ds = T::BenchmarkHelper::prepare_stakes(&a, T::MaxDeposits::get().min(count));
+
+		<Pallet<T>>::stake(
+			RawOrigin::Signed(a.clone()).into(),
+			count as Balance * UNIT,
+			count as Balance * UNIT,
+			ds.clone(),
+		)
+		.unwrap();
+
+		// The worst case:
+		//
+		// Claim all the unstakings which mature in different sessions.
+		unstake_in_sessions::<T>(&a, count, &ds);
+		<frame_system::Pallet<T>>::set_block_number(<Pallet<T>>::claimable_at(
+			<frame_system::Pallet<T>>::block_number(),
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()));
+
+		assert!(<Pallet<T>>::ledger_of(&a).is_none());
+	}
+
+	#[benchmark]
+	fn collect() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		T::BenchmarkHelper::prepare_stakes(&a, 0);
+		T::BenchmarkHelper::prepare_keys(&a);
+		<Pallet<T>>::stake(
+			RawOrigin::Signed(a.clone()).into(),
+			T::MinCollatorStake::get(),
+			0,
+			Vec::new(),
+		)
+		.unwrap();
+
+		<ChilledCommissions<T>>::insert(&a, Perbill::zero());
+
+		// The worst case:
+		//
+		// Re-collect after chilling and schedule a commission increase.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), T::MaxCommissionDelta::get().min(T::MaxCommission::get()));
+
+		assert!(<PendingCommissions<T>>::contains_key(&a));
+		assert!(!<ChilledCommissions<T>>::contains_key(&a));
+	}
+
+	#[benchmark]
+	fn nominate() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		T::BenchmarkHelper::prepare_stakes(&a, 0);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+		<Collators<T>>::insert(&a, Perbill::zero());
+
+		// The worst case:
+		//
+		// Replace the maximum count of the nominations.
+		nominate_max::<T>(&a);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), a.clone());
+
+		assert_eq!(
+			<Pallet<T>>::nominator_of(&a).map(|n| n.into_inner()),
+			Some(vec![(a, Perbill::one())])
+		);
+	}
+
+	#[benchmark]
+	fn nominate_multiple(x: Linear<1, 16>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let x = x.min(T::MaxNominations::get());
+		let cs = (0..x)
+			.map(|i| {
+				let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+				<Collators<T>>::insert(&c, Perbill::zero());
+
+				c
+			})
+			.collect::<Vec<_>>();
+
+		T::BenchmarkHelper::prepare_stakes(&a, 0);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+		nominate_max::<T>(&a);
+
+		// The worst case:
+		//
+		// Replace the maximum count of the nominations, and nominate `x` collators with an even
+		// split.
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), cs, Vec::new());
+
+		assert_eq!(<Pallet<T>>::nominator_of(&a).map(|n| n.len() as u32), Some(x));
+	}
+
+	#[benchmark]
+	fn chill() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		T::BenchmarkHelper::prepare_stakes(&a, 0);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+		<Collators<T>>::insert(&a, Perbill::zero());
+
+		// The worst case:
+		//
+		// Remove the maximum count of the nominations.
+		nominate_max::<T>(&a);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()));
+
+		assert!(!<Collators<T>>::contains_key(&a));
+		assert!(!<Nominators<T>>::contains_key(&a));
+	}
+
+	#[benchmark]
+	fn cancel_deferred_slash(x: Linear<1, 100>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		<UnappliedSlashes<T>>::insert(
+			0,
+			(0..x)
+				.map(|_| UnappliedSlash {
+					collator: a.clone(),
+					fraction: Perbill::zero(),
+					nominators: BoundedVec::new(),
+				})
+				.collect::<Vec<_>>(),
+		);
+
+		// The worst case:
+		//
+		// Cancel all the slashes of the session.
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, (0..x).collect());
+
+		assert!(!<UnappliedSlashes<T>>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn on_offence(x: Linear<0, 32>) {
+		let c = frame_benchmarking::account::<T::AccountId>("collator", 0, 0);
+		let e = full_exposure::<T>(x.min(T::MaxNominatorsPerCollator::get()));
+
+		// The worst case:
+		//
+		// None of the nominators nominates the collator any more, so all of their powers need to
+		// be calculated.
+		for n in e.nominators.iter().map(|n| n.who.clone()) {
+			T::BenchmarkHelper::prepare_stakes(&n, 0);
+			<Pallet<T>>::stake(RawOrigin::Signed(n.clone()).into(), UNIT, UNIT, Vec::new())
+				.unwrap();
+			nominate_max::<T>(&n);
+		}
+
+		<HistoricalExposures<T>>::insert(0, &c, e);
+
+		#[block]
+		{
+			<Pallet<T> as OnOffenceHandler<_, _, _>>::on_offence(
+				&[OffenceDetails { offender: c.clone(), reporters: Vec::new() }],
+				&[Perbill::from_percent(10)],
+				0,
+				DisableStrategy::Never,
+			);
+		}
+	}
+
+	#[benchmark]
+	fn apply_slash(x: Linear<0, 32>) {
+		let c = frame_benchmarking::account::<T::AccountId>("collator", 0, 0);
+		let e = full_exposure::<T>(x.min(T::MaxNominatorsPerCollator::get()));
+
+		// The worst case:
+		//
+		// All the stakers stake all kinds of the assets, have an unstaking and nominate the
+		// maximum count of the collators, whose backings need to be updated.
+		for n in e.nominators.iter().map(|n| n.who.clone()).chain([c.clone()]) {
+			let ds = T::BenchmarkHelper::prepare_stakes(&n, 16);
+
+			<Pallet<T>>::stake(RawOrigin::Signed(n.clone()).into(), 2 * UNIT, 2 * UNIT, ds)
+				.unwrap();
+			<Pallet<T>>::unstake(RawOrigin::Signed(n.clone()).into(), UNIT, UNIT, Vec::new())
+				.unwrap();
+			nominate_max::<T>(&n);
+		}
+
+		<UnappliedSlashes<T>>::insert(
+			0,
+			vec![UnappliedSlash {
+				collator: c.clone(),
+				fraction: Perbill::from_percent(10),
+				nominators: BoundedVec::truncate_from(
+					e.nominators.into_iter().map(|n| (n.who, Perbill::one())).collect(),
+				),
+			}],
+		);
+
+		#[block]
+		{
+			<Pallet<T>>::apply_unapplied_slashes(0);
+		}
+
+		assert!(!<UnappliedSlashes<T>>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn payout(x: Linear<1, 100>) {
+		let reward_map = (0..x)
+			.map(|i| {
+				let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+				<Collators<T>>::insert(&c, Perbill::from_percent(10));
+				<HistoricalExposures<T>>::insert(
+					0,
+					&c,
+					full_exposure::<T>(T::MaxNominatorsPerCollator::get()),
+				);
+
+				(c, 20)
+			})
+			.collect::<BTreeMap<_, _>>();
+
+		<RewardPoints<T>>::put((20 * x, reward_map));
+
+		#[block]
+		{
+			// 6 hours.
+			<Pallet<T>>::payout(0, 6 * 60 * 60 * 1_000, <ElapsedTime<T>>::get());
+		}
+
+		assert_eq!(<PendingRewards<T>>::iter_prefix(0).count(), x as usize);
+	}
+
+	#[benchmark]
+	fn payout_stakers(x: Linear<0, 32>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let c = frame_benchmarking::account::<T::AccountId>("collator", 0, 0);
+		let e = full_exposure::<T>(x.min(T::MaxNominatorsPerCollator::get()));
+
+		<Collators<T>>::insert(&c, Perbill::zero());
+
+		// The worst case:
+		//
+		// All the stakers compound their rewards, which need to update the backings.
+		for n in e.nominators.iter().map(|n| n.who.clone()).chain([c.clone()]) {
+			T::BenchmarkHelper::prepare_stakes(&n, 0);
+			<Pallet<T>>::stake(RawOrigin::Signed(n.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+			<Pallet<T>>::set_payee(RawOrigin::Signed(n.clone()).into(), RewardDestination::Staked)
+				.unwrap();
+			<Pallet<T>>::nominate(RawOrigin::Signed(n).into(), c.clone()).unwrap();
+		}
+
+		<PendingRewards<T>>::insert(
+			0,
+			&c,
+			PendingReward {
+				commission: Perbill::from_percent(10),
+				total: 1_000 * UNIT,
+				exposure: e,
+			},
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a), c.clone(), 0);
+
+		assert!(!<PendingRewards<T>>::contains_key(0, &c));
+	}
+
+	#[benchmark]
+	fn set_payee() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		T::BenchmarkHelper::prepare_stakes(&a, 0);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), RewardDestination::Account(a.clone()));
+
+		assert_eq!(<Pallet<T>>::payee_of(&a), RewardDestination::Account(a));
+	}
+
+	#[benchmark]
+	fn set_collator_count() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 100);
+
+		assert_eq!(<CollatorCount<T>>::get(), 100);
+	}
+
+	#[benchmark]
+	fn set_invulnerables(x: Linear<0, 100>) {
+		let is = (0..x)
+			.map(|i| frame_benchmarking::account::<T::AccountId>("invulnerable", i, 0))
+			.collect::<Vec<_>>();
+
+		<CollatorCount<T>>::put(x);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, is);
+
+		assert_eq!(<Invulnerables<T>>::get().len(), x as usize);
+	}
+
+	#[benchmark]
+	fn force_chill() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		T::BenchmarkHelper::prepare_stakes(&a, 0);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+		<Collators<T>>::insert(&a, Perbill::zero());
+
+		// The worst case:
+		//
+		// Remove the maximum count of the nominations.
+		nominate_max::<T>(&a);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, a.clone());
+
+		assert!(!<Collators<T>>::contains_key(&a));
+		assert!(!<Nominators<T>>::contains_key(&a));
+	}
+
+	#[benchmark]
+	fn force_unstake(x: Linear<0, 16>) {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+		let ds = T::BenchmarkHelper::prepare_stakes(&a, x);
+
+		// The worst case:
+		//
+		// Release all kinds of the assets and remove the maximum count of the nominations.
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, UNIT, ds).unwrap();
+		nominate_max::<T>(&a);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, a.clone());
+
+		assert!(<Pallet<T>>::ledger_of(&a).is_none());
+	}
+
+	#[benchmark]
+	fn lock_and_stake() {
+		let a = frame_benchmarking::whitelisted_caller::<T::AccountId>();
+
+		// The worst case:
+		//
+		// Take the last deposit slot and update the backings of all the targets.
+		T::BenchmarkHelper::prepare_stakes(&a, T::MaxDeposits::get() - 1);
+		<Pallet<T>>::stake(RawOrigin::Signed(a.clone()).into(), UNIT, 0, Vec::new()).unwrap();
+		nominate_max::<T>(&a);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(a.clone()), UNIT, 1);
+
+		assert_eq!(<Pallet<T>>::ledger_of(&a).unwrap().staked_deposits.len(), 1);
+	}
+
+	#[benchmark]
+	fn expire_rewards(x: Linear<0, 100>) {
+		(0..x).for_each(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<PendingRewards<T>>::insert(
+				0,
+				&c,
+				PendingReward {
+					commission: Perbill::from_percent(10),
+					total: 1_000 * UNIT,
+					exposure: full_exposure::<T>(T::MaxNominatorsPerCollator::get()),
+				},
+			);
+		});
+
+		#[block]
+		{
+			<Pallet<T>>::expire_rewards(T::HistoryDepth::get());
+		}
+
+		assert_eq!(<PendingRewards<T>>::iter_prefix(0).count(), 0);
+	}
+
+	#[benchmark]
+	fn prune_history(x: Linear<0, 100>) {
+		(0..x).for_each(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<HistoricalExposures<T>>::insert(
+				0,
+				&c,
+				full_exposure::<T>(T::MaxNominatorsPerCollator::get()),
+			);
+			<HistoricalRewardPoints<T>>::insert(0, c, 20);
+		});
+		<SessionSummaries<T>>::insert(0, SessionSummary::default());
+
+		#[block]
+		{
+			<Pallet<T>>::prune_history(T::HistoryDepth::get());
+		}
+
+		assert_eq!(<HistoricalExposures<T>>::iter_prefix(0).count(), 0);
+	}
+
+	#[benchmark]
+	fn chill_inactive_collators(x: Linear<0, 100>) {
+		// The worst case:
+		//
+		// All the elected collators reach the inactive limit in this session.
+		(0..x).for_each(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<Collators<T>>::insert(&c, Perbill::zero());
+			<HistoricalExposures<T>>::insert(0, &c, full_exposure::<T>(0));
+			<InactiveSessions<T>>::insert(&c, T::MaxInactiveSessions::get().saturating_sub(1));
+		});
+
+		#[block]
+		{
+			<Pallet<T>>::chill_inactive_collators(0);
+		}
+	}
+
+	#[benchmark]
+	fn clean_old_session(x: Linear<0, 100>) {
+		(0..x).for_each(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<RewardPoints<T>>::mutate(|(t, m)| {
+				*t += 20;
+
+				m.insert(c, 20);
+			});
+		});
+
+		#[block]
+		{
+			<Pallet<T>>::clean_old_session(0);
+		}
+
+		assert_eq!(<RewardPoints<T>>::get().0, 0);
+		assert_eq!(<HistoricalRewardPoints<T>>::iter_prefix(0).count(), x as usize);
+	}
+
+	#[benchmark]
+	fn apply_commission_changes(x: Linear<0, 100>) {
+		// The worst case:
+		//
+		// All the changes are due.
+		(0..x).for_each(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<Collators<T>>::insert(&c, Perbill::zero());
+			<PendingCommissions<T>>::insert(&c, (Perbill::from_percent(1), 0));
+		});
+
+		#[block]
+		{
+			<Pallet<T>>::apply_commission_changes(0);
+		}
+
+		assert_eq!(<PendingCommissions<T>>::iter_keys().count(), 0);
+	}
+
+	#[benchmark]
+	fn drop_underbonded_collators(x: Linear<0, 100>) {
+		// The worst case:
+		//
+		// All the candidates are under-bonded.
+		(0..x).for_each(|i| {
+			let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+			<Collators<T>>::insert(&c, Perbill::zero());
+		});
+
+		#[block]
+		{
+			<Pallet<T>>::drop_underbonded_collators();
+		}
+
+		assert_eq!(<Collators<T>>::iter_keys().count(), 0);
+	}
+
+	#[benchmark]
+	fn elect(x: Linear<1, 100>, y: Linear<0, 1_000>) {
+		let cs = (0..x)
+			.map(|i| {
+				let c = frame_benchmarking::account::<T::AccountId>("collator", i, 0);
+
+				<Collators<T>>::insert(&c, Perbill::zero());
+
+				c
+			})
+			.collect::<Vec<_>>();
+
+		// The worst case:
+		//
+		// Elect all the collators, so that all the `y` nominations need to be assembled.
+		(0..y).for_each(|i| {
+			let n = frame_benchmarking::account::<T::AccountId>("nominator", i, 0);
+
+			T::BenchmarkHelper::prepare_stakes(&n, 0);
+			<Pallet<T>>::stake(RawOrigin::Signed(n.clone()).into(), UNIT, UNIT, Vec::new())
+				.unwrap();
+			<Pallet<T>>::nominate(RawOrigin::Signed(n).into(), cs[(i % x) as usize].clone())
+				.unwrap();
+		});
+		<CollatorCount<T>>::put(x);
+
+		// The exposures of the previous election need to be cleared as well.
+		cs.iter().for_each(|c| <Exposures<T>>::insert(c, full_exposure::<T>(0)));
+
+		#[block]
+		{
+			<Pallet<T>>::elect(0);
+		}
+
+		assert_eq!(<Exposures<T>>::iter_keys().count(), x as usize);
+		assert_eq!(<HistoricalExposures<T>>::iter_prefix(0).count(), x as usize);
+	}
+}
@@ -27,13 +27,64 @@
 //! - RING: Darwinia's native token
 //! - KTON: Darwinia's commitment token
 //! - Deposit: Locking RINGs' ticket
-
-// TODO: weight
-// TODO: nomination upper limit
+//!
+//! ### Unstaking:
+//! The unstaked assets become claimable after [`Config::MinStakingDuration`] blocks, rounded up
+//! to the end of the session by the [`Config::NextSessionRotation`].
+//! They are kept in [`Unstakings`] outside the ledger, and the unstakings which mature in the
+//! same session are merged into a single entry. So, there is no limit on the unstaking count.
+//!
+//! ### Slashing:
+//! The offences are reported through the [`OnOffenceHandler`].
+//! The offender's ledger and the shares of its nominators' ledgers which back it in the
+//! offence's session will be slashed after [`Config::SlashDeferDuration`] sessions, unless the
+//! slash is cancelled by the [`Config::SlashCancelOrigin`].
+//! The inactive collators are slashed through the same path, see the election below.
+//!
+//! ### Nomination:
+//! A nominator could split their power across up to [`Config::MaxNominations`] collators.
+//! Only the top [`Config::MaxNominatorsPerCollator`] nominators of a collator, ranked by the
+//! power backing that collator, count towards its exposure and earn rewards.
+//!
+//! The stakes are converted into power by the [`Config::PowerCalculator`].
+//! The RING and KTON backing each collator are indexed whenever the stakes or the nominations
+//! change. So, the election only needs to sort the collators by the cached [`TotalBackings`].
+//!
+//! ### Election:
+//! A collator candidate must have set its session keys and staked at least
+//! [`Config::MinCollatorStake`] RING by itself, and its commission must not exceed the
+//! [`Config::MaxCommission`]. The candidates which fall below the self-stake will be dropped at
+//! the beginning of the next election.
+//! A collator's commission increase is capped by the [`Config::MaxCommissionDelta`] and delayed
+//! by the [`Config::CommissionChangeDelay`] sessions, so its nominators have time to move.
+//! Chilling doesn't reset the delay, and the rewards are paid with the commission recorded at
+//! the election.
+//! The [`Invulnerables`] are always elected, ahead of the power-ranked candidates.
+//! The [`Config::ManagerOrigin`] could adjust the collator count and the invulnerables, or
+//! remove a bad candidate through the [`Call::force_chill`] and the [`Call::force_unstake`].
+//! An elected collator which authors fewer than [`Config::MinAuthoredBlocks`] blocks in
+//! [`Config::MaxInactiveSessions`] consecutive sessions will be chilled automatically, and
+//! slashed by the [`Config::InactivitySlashFraction`].
+//!
+//! ### Reward:
+//! The session rewards are recorded per collator and per session in [`PendingRewards`].
+//! Anyone could pay them out to the stakers through the [`Call::payout_stakers`].
+//! The rewards which are not paid out within [`Config::HistoryDepth`] sessions will expire into
+//! the [`Config::RewardRemainder`].
+//! The stakers could route their rewards to another account or compound them through the
+//! [`Call::set_payee`].
+//! The exposures, the reward points and the payout figures of the last
+//! [`Config::HistoryDepth`] sessions are archived in the [`HistoricalExposures`], the
+//! [`HistoricalRewardPoints`] and the [`SessionSummaries`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
+pub mod runtime_api;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 mod weights;
 pub use weights::WeightInfo;
 
@@ -41,15 +92,18 @@ pub use weights::WeightInfo;
 use core::fmt::Debug;
 // crates.io
 use codec::FullCodec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 // darwinia
 use dc_inflation::TOTAL_SUPPLY;
 use dc_types::{Balance, Moment, UNIT};
 // substrate
 use frame_support::{
+	dispatch::DispatchClass,
 	log,
 	pallet_prelude::*,
-	traits::{Currency, OnUnbalanced, UnixTime},
-	EqNoBound, PalletId, PartialEqNoBound,
+	traits::{Contains, Currency, EstimateNextSessionRotation, Imbalance, OnUnbalanced, UnixTime},
+	DefaultNoBound, EqNoBound, PalletId, PartialEqNoBound,
 };
 use frame_system::pallet_prelude::*;
 #[cfg(feature = "std")]
@@ -58,10 +112,16 @@ use sp_runtime::{
 	traits::{AccountIdConversion, Convert},
 	Perbill, Perquintill,
 };
+use sp_staking::{
+	offence::{DisableStrategy, OffenceDetails, OnOffenceHandler},
+	SessionIndex,
+};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
-type RewardPoint = u32;
-type Power = u32;
+/// Collator's reward point.
+pub type RewardPoint = u32;
+/// Stake power.
+pub type Power = u32;
 
 type DepositId<T> = <<T as Config>::Deposit as Stake>::Item;
 type NegativeImbalance<T> = <<T as Config>::RingCurrency as Currency<
@@ -97,6 +157,106 @@ pub trait StakeExt: Stake {
 	/// Get the staked amount.
 	fn amount(who: &Self::AccountId, item: Self::Item) -> Result<Self::Amount, DispatchError>;
 }
+/// Lock trait that the deposit must implement.
+///
+/// Provide a way to create the deposits.
+pub trait Lock: Stake {
+	/// Lock the RING for `months` to create a new deposit.
+	///
+	/// Return the id of the new deposit.
+	fn lock(
+		who: &Self::AccountId,
+		amount: Balance,
+		months: u8,
+	) -> Result<Self::Item, DispatchError>;
+}
+/// Slash trait that stake items must be implemented.
+///
+/// Provide a way to punish the stakers.
+pub trait Slash: Stake {
+	/// Slash the `fraction` of the stake item from the staking pool.
+	///
+	/// For RING and KTON, the item is the amount.
+	/// For deposit, the item is the deposit id.
+	///
+	/// Return the actual slashed amount.
+	fn slash(
+		who: &Self::AccountId,
+		item: Self::Item,
+		fraction: Perbill,
+	) -> Result<Balance, DispatchError>;
+}
+
+/// Refresh trait that the staking pallet implements.
+///
+/// Provide a way to update the backings, after a staked item has been changed outside the staking
+/// pallet.
+pub trait RefreshBackings<AccountId> {
+	/// Update the backings of the staker's nominations.
+	fn refresh_backings(who: &AccountId);
+}
+impl<AccountId> RefreshBackings<AccountId> for () {
+	fn refresh_backings(_: &AccountId) {}
+}
+
+/// Power calculation interface.
+///
+/// The stakes are converted into power, which ranks the collators and splits the rewards.
+pub trait PowerCalculator<AccountId, DepositId> {
+	/// Calculate the RING that a staked deposit counts as.
+	///
+	/// `amount` is the face value of the deposit.
+	/// The result is evaluated whenever the staker's backings are refreshed.
+	fn deposit2ring(who: &AccountId, deposit: DepositId, amount: Balance) -> Balance;
+
+	/// Calculate the power of the backing RING and KTON.
+	///
+	/// The pools are the total staked RING and KTON, where the deposits are counted at their
+	/// face value.
+	fn backing2power(backing: &Backing, ring_pool: Balance, kton_pool: Balance) -> Power;
+}
+/// The default power calculation.
+///
+/// Deposits count as their face value RING, and the power is split evenly between the RING pool
+/// and the KTON pool.
+impl<AccountId, DepositId> PowerCalculator<AccountId, DepositId> for () {
+	fn deposit2ring(_: &AccountId, _: DepositId, amount: Balance) -> Balance {
+		amount
+	}
+
+	// Power is a mixture of RING and KTON.
+	// - `total_ring_power = (amount / total_staked_ring) * HALF_POWER`
+	// - `total_kton_power = (amount / total_staked_kton) * HALF_POWER`
+	fn backing2power(backing: &Backing, ring_pool: Balance, kton_pool: Balance) -> Power {
+		let balance2power = |amount, pool: Balance| {
+			(Perquintill::from_rational(amount, pool.max(1)) * 500_000_000_u128) as Power
+		};
+
+		balance2power(backing.ring, ring_pool) + balance2power(backing.kton, kton_pool)
+	}
+}
+
+/// Prepare the stakes for the benchmarks.
+///
+/// The staking pallet is unable to mint KTON or create deposits by itself.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, DepositId> {
+	/// Fund the account with enough RING and KTON, then create `count` deposits for it.
+	///
+	/// Return the ids of the new deposits.
+	fn prepare_stakes(who: &AccountId, count: u32) -> Vec<DepositId>;
+
+	/// Register the session keys for the account.
+	fn prepare_keys(who: &AccountId);
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl<AccountId, DepositId> BenchmarkHelper<AccountId, DepositId> for () {
+	fn prepare_stakes(_: &AccountId, _: u32) -> Vec<DepositId> {
+		Vec::new()
+	}
+
+	fn prepare_keys(_: &AccountId) {}
+}
 
 /// A convertor from collators id. Since this pallet does not have stash/controller, this is
 /// just identity.
@@ -107,6 +267,17 @@ impl<T> Convert<T, Option<T>> for IdentityCollator {
 	}
 }
 
+/// A [`Config::KeysRegistry`] which checks the [`pallet_session::NextKeys`].
+pub struct SessionKeysRegistry<T>(PhantomData<T>);
+impl<T> Contains<T::AccountId> for SessionKeysRegistry<T>
+where
+	T: pallet_session::Config<ValidatorId = <T as frame_system::Config>::AccountId>,
+{
+	fn contains(who: &T::AccountId) -> bool {
+		<pallet_session::NextKeys<T>>::contains_key(who)
+	}
+}
+
 /// Staking ledger.
 #[derive(PartialEqNoBound, EqNoBound, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
 #[scale_info(skip_type_params(T))]
@@ -120,37 +291,253 @@ where
 	pub staked_kton: Balance,
 	/// Staked deposits.
 	pub staked_deposits: BoundedVec<DepositId<T>, T::MaxDeposits>,
+}
+impl<T> Ledger<T>
+where
+	T: Config,
+{
+	fn is_empty(&self) -> bool {
+		self.staked_ring == 0 && self.staked_kton == 0 && self.staked_deposits.is_empty()
+	}
+}
+
+/// The assets in unstaking process, which become claimable at the same block.
+#[derive(
+	DefaultNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebug,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct Unstaking<T>
+where
+	T: Config,
+{
 	/// The RING in unstaking process.
-	pub unstaking_ring: BoundedVec<(Balance, T::BlockNumber), T::MaxUnstakings>,
+	pub ring: Balance,
 	/// The KTON in unstaking process.
-	pub unstaking_kton: BoundedVec<(Balance, T::BlockNumber), T::MaxUnstakings>,
-	/// The deposit in unstaking process.
-	pub unstaking_deposits: BoundedVec<(DepositId<T>, T::BlockNumber), T::MaxUnstakings>,
+	pub kton: Balance,
+	/// The deposits in unstaking process.
+	pub deposits: BoundedVec<DepositId<T>, T::MaxDeposits>,
 }
-impl<T> Ledger<T>
+impl<T> Unstaking<T>
 where
 	T: Config,
 {
 	fn is_empty(&self) -> bool {
-		self.staked_ring == 0
-			&& self.staked_kton == 0
-			&& self.staked_deposits.is_empty()
-			&& self.unstaking_ring.is_empty()
-			&& self.unstaking_kton.is_empty()
-			&& self.unstaking_deposits.is_empty()
+		self.ring == 0 && self.kton == 0 && self.deposits.is_empty()
+	}
+}
+
+/// The exposure layout which didn't record the collator's commission.
+///
+/// Only used by the migrations.
+#[derive(PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[scale_info(skip_type_params(T))]
+pub struct LegacyExposure<T>
+where
+	T: Config,
+{
+	/// The total power backing this collator.
+	pub total: Power,
+	/// Nominators' stake power.
+	pub nominators: BoundedVec<IndividualExposure<T::AccountId>, T::MaxNominatorsPerCollator>,
+}
+
+/// The staking ledger layout which kept the unstaking assets inside the ledger.
+///
+/// Only used by the migrations, use [`Pallet::split_legacy_ledger`] to convert it.
+#[derive(PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[scale_info(skip_type_params(T))]
+pub struct LegacyLedger<T>
+where
+	T: Config,
+{
+	/// Staked RING.
+	pub staked_ring: Balance,
+	/// Staked KTON.
+	pub staked_kton: Balance,
+	/// Staked deposits.
+	pub staked_deposits: BoundedVec<DepositId<T>, T::MaxDeposits>,
+	/// The RING in unstaking process.
+	pub unstaking_ring: Vec<(Balance, T::BlockNumber)>,
+	/// The KTON in unstaking process.
+	pub unstaking_kton: Vec<(Balance, T::BlockNumber)>,
+	/// The deposit in unstaking process.
+	pub unstaking_deposits: Vec<(DepositId<T>, T::BlockNumber)>,
+}
+
+/// Where the staker's rewards go.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub enum RewardDestination<AccountId> {
+	/// Pay into the specified account as free RING.
+	Account(AccountId),
+	/// Pay into the staker's account and stake it, so the rewards compound.
+	Staked,
+	/// Pay into the staker's account as free RING.
+	Free,
+}
+impl<AccountId> Default for RewardDestination<AccountId> {
+	fn default() -> Self {
+		Self::Free
+	}
+}
+
+// Serialize the amounts as strings, the JSON clients lose precision beyond 2^53.
+#[cfg(feature = "std")]
+mod serde_balance {
+	// crates.io
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+	// darwinia
+	use dc_types::Balance;
+
+	pub fn serialize<S>(balance: &Balance, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_str(&balance.to_string())
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Balance, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+	}
+
+	// The `(amount, block number)` pairs.
+	pub mod pairs {
+		// crates.io
+		use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+		// darwinia
+		use dc_types::Balance;
+
+		pub fn serialize<S, N>(pairs: &[(Balance, N)], serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+			N: Serialize,
+		{
+			serializer.collect_seq(pairs.iter().map(|(a, n)| (a.to_string(), n)))
+		}
+
+		pub fn deserialize<'de, D, N>(deserializer: D) -> Result<Vec<(Balance, N)>, D::Error>
+		where
+			D: Deserializer<'de>,
+			N: Deserialize<'de>,
+		{
+			<Vec<(String, N)>>::deserialize(deserializer)?
+				.into_iter()
+				.map(|(a, n)| Ok((a.parse().map_err(D::Error::custom)?, n)))
+				.collect()
+		}
 	}
 }
 
+/// A summary of the staker's ledger.
+///
+/// The deposits are counted as RING.
+///
+/// The amounts are serialized as strings.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "BlockNumber: Serialize",
+		deserialize = "BlockNumber: Deserialize<'de>"
+	))
+)]
+pub struct LedgerSummary<BlockNumber> {
+	/// Staked RING.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub staked_ring: Balance,
+	/// Staked KTON.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub staked_kton: Balance,
+	/// The RING in unstaking process and the block number at which it becomes claimable.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance::pairs"))]
+	pub unstaking_ring: Vec<(Balance, BlockNumber)>,
+	/// The KTON in unstaking process and the block number at which it becomes claimable.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance::pairs"))]
+	pub unstaking_kton: Vec<(Balance, BlockNumber)>,
+}
+
+/// The stakes backing a collator.
+///
+/// The deposits are counted as RING.
+#[derive(
+	Clone, Copy, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug,
+)]
+pub struct Backing {
+	/// Backing RING.
+	pub ring: Balance,
+	/// Backing KTON.
+	pub kton: Balance,
+}
+
 /// A snapshot of the stake backing a single collator in the system.
-#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
-pub struct Exposure<AccountId> {
+#[derive(PartialEqNoBound, EqNoBound, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+#[scale_info(skip_type_params(T))]
+pub struct Exposure<T>
+where
+	T: Config,
+{
+	/// The collator's commission, recorded at the election.
+	///
+	/// The invulnerables which have never collected take a 100% commission.
+	pub commission: Perbill,
+	/// The total power backing this collator.
+	pub total: Power,
+	/// Nominators' stake power.
+	pub nominators: BoundedVec<IndividualExposure<T::AccountId>, T::MaxNominatorsPerCollator>,
+}
+/// A collator's reward of a session, which is waiting to be paid out.
+#[derive(PartialEqNoBound, EqNoBound, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+#[scale_info(skip_type_params(T))]
+pub struct PendingReward<T>
+where
+	T: Config,
+{
+	/// The collator's commission of the session.
+	pub commission: Perbill,
+	/// The total reward of the collator and its nominators.
+	pub total: Balance,
+	/// The collator's exposure of the session.
+	pub exposure: Exposure<T>,
+}
+
+/// The archived payout figures of a session.
+///
+/// The amounts are serialized as strings.
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SessionSummary {
+	/// The RING minted for the session.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub inflation: Balance,
+	/// The part of the inflation which was recorded as the collators' rewards.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub payout: Balance,
+	/// The total reward points of the session.
+	pub reward_points: RewardPoint,
+}
+
+/// A summary of the collator's exposure.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExposureSummary<AccountId> {
 	/// The total power backing this collator.
 	pub total: Power,
 	/// Nominators' stake power.
 	pub nominators: Vec<IndividualExposure<AccountId>>,
 }
 /// A snapshot of the staker's state.
-#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct IndividualExposure<AccountId> {
 	/// Nominator.
 	pub who: AccountId,
@@ -158,6 +545,24 @@ pub struct IndividualExposure<AccountId> {
 	pub value: Power,
 }
 
+/// A slash which is waiting to be applied.
+#[derive(PartialEqNoBound, EqNoBound, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+#[scale_info(skip_type_params(T))]
+pub struct UnappliedSlash<T>
+where
+	T: Config,
+{
+	/// The offending collator.
+	pub collator: T::AccountId,
+	/// The fraction of the stakes to be slashed.
+	pub fraction: Perbill,
+	/// The collator's nominators at the time of the offence, along with the shares of their
+	/// stakes which back the collator.
+	///
+	/// Only these shares will be slashed.
+	pub nominators: BoundedVec<(T::AccountId, Perbill), T::MaxNominatorsPerCollator>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	// darwinia
@@ -168,6 +573,9 @@ pub mod pallet {
 		/// Override the [`frame_system::Config::RuntimeEvent`].
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
 		/// Unix time getter.
 		type UnixTime: UnixTime;
 
@@ -182,18 +590,29 @@ pub mod pallet {
 		type RewardRemainder: OnUnbalanced<NegativeImbalance<Self>>;
 
 		/// RING [`Stake`] interface.
-		type Ring: Stake<AccountId = Self::AccountId, Item = Balance>;
+		type Ring: Stake<AccountId = Self::AccountId, Item = Balance> + Slash;
 
 		/// KTON [`Stake`] interface.
-		type Kton: Stake<AccountId = Self::AccountId, Item = Balance>;
+		type Kton: Stake<AccountId = Self::AccountId, Item = Balance> + Slash;
 
 		/// Deposit [`StakeExt`] interface.
-		type Deposit: StakeExt<AccountId = Self::AccountId, Amount = Balance>;
+		type Deposit: StakeExt<AccountId = Self::AccountId, Amount = Balance> + Lock + Slash;
+
+		/// Stake power calculator.
+		///
+		/// Use `()` for the default calculation.
+		type PowerCalculator: PowerCalculator<Self::AccountId, DepositId<Self>>;
 
 		/// Minimum time to stake at least.
 		#[pallet::constant]
 		type MinStakingDuration: Get<Self::BlockNumber>;
 
+		/// Session rotation estimator.
+		///
+		/// The unstakings are kept until the end of the session in which they mature, so the
+		/// ones of the same session could be merged. Use `()` to keep them by block.
+		type NextSessionRotation: EstimateNextSessionRotation<Self::BlockNumber>;
+
 		/// The percentage of the total payout that is distributed to stakers.
 		///
 		/// Usually, the rest goes to the treasury.
@@ -204,9 +623,82 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxDeposits: Get<u32>;
 
-		/// Maximum unstaking/unbonding count.
+		/// Maximum collator count that a nominator could nominate at the same time.
+		#[pallet::constant]
+		type MaxNominations: Get<u32>;
+
+		/// Maximum nominator count that could back a collator.
+		///
+		/// Only the top nominators ranked by power will be counted, the rest will be evicted.
+		#[pallet::constant]
+		type MaxNominatorsPerCollator: Get<u32>;
+
+		/// Origin that can cancel the deferred slashes.
+		type SlashCancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin that can manage the collator set.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Minimum RING that a collator must stake by itself.
+		///
+		/// The deposits are counted as RING.
+		#[pallet::constant]
+		type MinCollatorStake: Get<Balance>;
+
+		/// Maximum commission that a collator could charge.
+		#[pallet::constant]
+		type MaxCommission: Get<Perbill>;
+
+		/// Registry of the accounts which have set their session keys.
+		type KeysRegistry: Contains<Self::AccountId>;
+
+		/// Number of sessions that a commission increase is delayed by.
+		///
+		/// The nominators could move their nominations within this window.
+		#[pallet::constant]
+		type CommissionChangeDelay: Get<SessionIndex>;
+
+		/// Maximum commission increase of a single change.
+		#[pallet::constant]
+		type MaxCommissionDelta: Get<Perbill>;
+
+		/// The maximum fraction of the stakes that could be slashed for a single offence.
+		#[pallet::constant]
+		type MaxSlashFraction: Get<Perbill>;
+
+		/// Number of sessions that the slashes are deferred by.
+		///
+		/// Slashes could be cancelled within this window.
+		#[pallet::constant]
+		type SlashDeferDuration: Get<SessionIndex>;
+
+		/// Number of sessions that the pending rewards are kept for.
+		///
+		/// The rewards which are not paid out within this window will expire.
+		#[pallet::constant]
+		type HistoryDepth: Get<SessionIndex>;
+
+		/// Minimum number of blocks that an elected collator is expected to author in a session.
+		///
+		/// A collator which authors fewer blocks is inactive in that session.
+		/// Set it to zero to disable the removal of the inactive collators.
+		#[pallet::constant]
+		type MinAuthoredBlocks: Get<u32>;
+
+		/// Number of consecutive inactive sessions after which a collator will be chilled.
+		#[pallet::constant]
+		type MaxInactiveSessions: Get<SessionIndex>;
+
+		/// Fraction of the stakes to slash from a collator which is chilled for the inactivity.
+		///
+		/// The slash is deferred and could be cancelled like the ones of the reported offences.
+		/// Set it to zero to only chill the inactive collators.
 		#[pallet::constant]
-		type MaxUnstakings: Get<u32>;
+		type InactivitySlashFraction: Get<Perbill>;
+
+		/// Benchmark helper.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, DepositId<Self>>;
 	}
 
 	#[allow(missing_docs)]
@@ -229,22 +721,104 @@ pub mod pallet {
 		},
 		/// A payout has been made for the staker.
 		Payout { staker: T::AccountId, ring_amount: Balance },
+		/// The collator's reward of the session has been recorded.
+		RewardRecorded { collator: T::AccountId, session: SessionIndex, ring_amount: Balance },
+		/// The unclaimed rewards of the session have expired.
+		RewardExpired { session: SessionIndex, ring_amount: Balance },
+		/// A collator candidate has been dropped, since its self-stake is below the
+		/// [`Config::MinCollatorStake`].
+		CollatorDropped { collator: T::AccountId },
+		/// A commission increase has been scheduled, which will be applied at the given session.
+		CommissionChangeScheduled {
+			collator: T::AccountId,
+			commission: Perbill,
+			session: SessionIndex,
+		},
+		/// A commission change has been applied.
+		CommissionChangeApplied { collator: T::AccountId, commission: Perbill },
 		/// A new collator set has been elected.
 		Elected { collators: Vec<T::AccountId> },
+		/// A nominator has been pushed out of the elected collator's exposure.
+		///
+		/// The nominator's power doesn't back the collator and earns no reward in this session.
+		NominatorEvicted { nominator: T::AccountId, collator: T::AccountId },
+		/// A slash has been deferred to the given session.
+		SlashDeferred { collator: T::AccountId, fraction: Perbill, session: SessionIndex },
+		/// Some deferred slashes have been cancelled.
+		SlashCancelled { session: SessionIndex, slash_indices: Vec<u32> },
+		/// The collator count has been set.
+		CollatorCountSet { count: u32 },
+		/// The invulnerables have been set.
+		InvulnerablesSet { invulnerables: Vec<T::AccountId> },
+		/// A staker has been chilled by the [`Config::ManagerOrigin`].
+		ForceChilled { staker: T::AccountId },
+		/// A staker's stakes have been released by the [`Config::ManagerOrigin`].
+		///
+		/// The `ring_amount` and `kton_amount` include the unstaking ones.
+		ForceUnstaked {
+			staker: T::AccountId,
+			ring_amount: Balance,
+			kton_amount: Balance,
+			deposits: Vec<DepositId<T>>,
+		},
+		/// A staker has been slashed.
+		///
+		/// The `ring_amount` includes the slashed deposits' RING.
+		Slashed { staker: T::AccountId, ring_amount: Balance, kton_amount: Balance },
+		/// A collator has been chilled, since it has been inactive for
+		/// [`Config::MaxInactiveSessions`] consecutive sessions.
+		InactiveCollatorChilled { collator: T::AccountId, inactive_sessions: SessionIndex },
+		/// An account has re-staked its unstaking assets.
+		Restaked {
+			staker: T::AccountId,
+			ring_amount: Balance,
+			kton_amount: Balance,
+			deposits: Vec<DepositId<T>>,
+		},
+		/// A collator candidate's commission has been set without delay.
+		CommissionSet { collator: T::AccountId, commission: Perbill },
+		/// A nominator's power has been split across the collators.
+		Nominated { nominator: T::AccountId, nominations: Vec<(T::AccountId, Perbill)> },
+		/// The destination of the staker's rewards has been set.
+		PayeeSet { staker: T::AccountId, destination: RewardDestination<T::AccountId> },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Exceed maximum deposit count.
 		ExceedMaxDeposits,
-		/// Exceed maximum unstaking/unbonding count.
-		ExceedMaxUnstakings,
 		/// Deposit not found.
 		DepositNotFound,
 		/// You are not a staker.
 		NotStaker,
 		/// Target is not a collator.
 		TargetNotCollator,
+		/// Nomination targets must not be empty.
+		EmptyTargets,
+		/// Exceed maximum nomination count.
+		ExceedMaxNominations,
+		/// Nomination targets must not contain duplicates.
+		DuplicateTarget,
+		/// Nomination weights must match the targets and sum up to 100%.
+		InvalidNominationWeights,
+		/// Slash indices must not be empty.
+		EmptySlashIndices,
+		/// Slash index is out of bounds.
+		InvalidSlashIndex,
+		/// No pending reward for the collator in the session.
+		///
+		/// It might have been paid out or expired.
+		NoPendingReward,
+		/// The invulnerables must not exceed the collator count.
+		ExceedCollatorCount,
+		/// The commission exceeds the [`Config::MaxCommission`].
+		CommissionTooHigh,
+		/// The self-stake is below the [`Config::MinCollatorStake`].
+		InsufficientSelfStake,
+		/// The session keys haven't been set.
+		NoSessionKeys,
+		/// The commission increase exceeds the [`Config::MaxCommissionDelta`].
+		ExceedMaxCommissionDelta,
 	}
 
 	/// All staking ledgers.
@@ -252,6 +826,29 @@ pub mod pallet {
 	#[pallet::getter(fn ledger_of)]
 	pub type Ledgers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Ledger<T>>;
 
+	/// The stakers' assets in unstaking process, keyed by the block number at which they become
+	/// claimable.
+	///
+	/// The unstakings which mature in the same session are merged into a single entry.
+	#[pallet::storage]
+	#[pallet::getter(fn unstaking_of)]
+	pub type Unstakings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::BlockNumber,
+		Unstaking<T>,
+	>;
+
+	/// The stakers' reward destinations.
+	///
+	/// The rewards will be paid as free RING to the staker's account by default.
+	#[pallet::storage]
+	#[pallet::getter(fn payee_of)]
+	pub type Payees<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
 	/// Total staked RING.
 	///
 	/// This will count RING + deposit(locking RING).
@@ -269,23 +866,70 @@ pub mod pallet {
 	#[pallet::getter(fn collator_of)]
 	pub type Collators<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill>;
 
-	/// Stakers' exposure.
+	/// The collators' commission increases which are waiting to be applied, along with the
+	/// session to be applied at.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_commission_of)]
+	pub type PendingCommissions<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (Perbill, SessionIndex)>;
+
+	/// The commissions of the chilled or dropped collators.
+	///
+	/// A re-collecting collator's commission increase is delayed against it, so chilling can't
+	/// bypass the [`Config::CommissionChangeDelay`]. It's removed together with the ledger.
+	#[pallet::storage]
+	#[pallet::getter(fn chilled_commission_of)]
+	pub type ChilledCommissions<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill>;
+
+	/// The index of the current session.
+	#[pallet::storage]
+	#[pallet::getter(fn current_session)]
+	pub type CurrentSession<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
+	/// The exposures of the latest elected collators.
+	///
+	/// These are the exposures of the upcoming session, see [`HistoricalExposures`] for the ones
+	/// of the active session.
 	#[pallet::storage]
-	#[pallet::unbounded]
 	#[pallet::getter(fn exposure_of)]
-	pub type Exposures<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, Exposure<T::AccountId>>;
+	pub type Exposures<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Exposure<T>>;
 
 	/// The ideal number of active collators.
 	#[pallet::storage]
 	#[pallet::getter(fn collator_count)]
 	pub type CollatorCount<T> = StorageValue<_, u32, ValueQuery>;
 
-	/// The map from nominator to their nomination preferences, namely the collator that
-	/// they wish to support.
+	/// The map from nominator to their nomination preferences, namely the collators that
+	/// they wish to support and the weight of their power that goes to each collator.
 	#[pallet::storage]
 	#[pallet::getter(fn nominator_of)]
-	pub type Nominators<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::AccountId>;
+	pub type Nominators<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(T::AccountId, Perbill), T::MaxNominations>,
+	>;
+
+	/// The reverse index of the nominations.
+	///
+	/// The stakes backing the collator from each nominator, split by the nomination weight.
+	#[pallet::storage]
+	#[pallet::getter(fn backing_of)]
+	pub type Backings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		Backing,
+		OptionQuery,
+	>;
+
+	/// The running total of the [`Backings`] of each collator.
+	#[pallet::storage]
+	#[pallet::getter(fn total_backing_of)]
+	pub type TotalBackings<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Backing, ValueQuery>;
 
 	/// Collator's reward points.
 	#[pallet::storage]
@@ -304,6 +948,77 @@ pub mod pallet {
 	#[pallet::getter(fn elapsed_time)]
 	pub type ElapsedTime<T: Config> = StorageValue<_, Moment, ValueQuery>;
 
+	/// The collators' rewards which are waiting to be paid out, keyed by the session.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_reward_of)]
+	pub type PendingRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		PendingReward<T>,
+	>;
+
+	/// The exposures of the collators, keyed by the session in which they are active.
+	///
+	/// They are archived at the election, see [`Pallet::elect`].
+	/// Only the last [`Config::HistoryDepth`] sessions are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn historical_exposure_of)]
+	pub type HistoricalExposures<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SessionIndex, Twox64Concat, T::AccountId, Exposure<T>>;
+
+	/// The archived reward points of the collators, keyed by the session.
+	///
+	/// Only the last [`Config::HistoryDepth`] sessions are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn historical_reward_points_of)]
+	pub type HistoricalRewardPoints<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::AccountId,
+		RewardPoint,
+		ValueQuery,
+	>;
+
+	/// The archived payout figures, keyed by the session.
+	///
+	/// Only the last [`Config::HistoryDepth`] sessions are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn session_summary_of)]
+	pub type SessionSummaries<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, SessionSummary>;
+
+	/// Number of blocks authored by each collator in the current session.
+	#[pallet::storage]
+	#[pallet::getter(fn authored_blocks_of)]
+	pub type AuthoredBlocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Number of consecutive sessions in which each elected collator has been inactive.
+	#[pallet::storage]
+	#[pallet::getter(fn inactive_sessions_of)]
+	pub type InactiveSessions<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, ValueQuery>;
+
+	/// The collators which will always be elected, ahead of the power-ranked candidates.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn invulnerables)]
+	pub type Invulnerables<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// All the slashes which are waiting to be applied, keyed by the session to be applied at.
+	///
+	/// Each reported offence adds one entry, so the count of the entries follows the count of the
+	/// offences. The nominators of each entry are bounded by [`Config::MaxNominatorsPerCollator`].
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn unapplied_slashes)]
+	pub type UnappliedSlashes<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, Vec<UnappliedSlash<T>>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Current timestamp.
@@ -334,10 +1049,10 @@ pub mod pallet {
 			self.collators.iter().for_each(|(who, stake)| {
 				<Pallet<T>>::stake(RawOrigin::Signed(who.to_owned()).into(), *stake, 0, Vec::new())
 					.expect("[pallet::staking] 0, genesis must be built; qed");
-				<Pallet<T>>::collect(RawOrigin::Signed(who.to_owned()).into(), Default::default())
-					.expect("[pallet::staking] 1, genesis must be built; qed");
+				// The session keys are not available yet, so insert the collator directly.
+				<Collators<T>>::insert(who, Perbill::zero());
 				<Pallet<T>>::nominate(RawOrigin::Signed(who.to_owned()).into(), who.to_owned())
-					.expect("[pallet::staking] 2, genesis must be built; qed");
+					.expect("[pallet::staking] 1, genesis must be built; qed");
 			});
 		}
 	}
@@ -351,7 +1066,7 @@ pub mod pallet {
 		///
 		/// This will transfer the stakes to a pallet/contact account.
 		#[pallet::call_index(0)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::stake(deposits.len() as _))]
 		pub fn stake(
 			origin: OriginFor<T>,
 			ring_amount: Balance,
@@ -374,9 +1089,6 @@ pub mod pallet {
 						staked_ring: Default::default(),
 						staked_kton: Default::default(),
 						staked_deposits: Default::default(),
-						unstaking_ring: Default::default(),
-						unstaking_kton: Default::default(),
-						unstaking_deposits: Default::default(),
 					});
 
 					l.as_mut().expect("[pallet::staking] `l` must be some; qed")
@@ -404,6 +1116,8 @@ pub mod pallet {
 				DispatchResult::Ok(())
 			})?;
 
+			Self::refresh_backings(&who);
+
 			Self::deposit_event(Event::Staked { staker: who, ring_amount, kton_amount, deposits });
 
 			Ok(())
@@ -411,7 +1125,7 @@ pub mod pallet {
 
 		/// Withdraw stakes from the staking pool.
 		#[pallet::call_index(1)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::unstake(deposits.len() as _))]
 		pub fn unstake(
 			origin: OriginFor<T>,
 			ring_amount: Balance,
@@ -426,30 +1140,42 @@ pub mod pallet {
 
 			<Ledgers<T>>::try_mutate(&who, |l| {
 				let l = l.as_mut().ok_or(<Error<T>>::NotStaker)?;
+				let claimable_at = Self::claimable_at(<frame_system::Pallet<T>>::block_number());
+				// Merge into the unstaking which matures in the same session.
+				let mut u = <Unstakings<T>>::get(&who, claimable_at).unwrap_or_default();
 
 				if ring_amount != 0 {
 					Self::unstake_token::<RingPool<T>>(
 						&mut l.staked_ring,
-						&mut l.unstaking_ring,
+						&mut u.ring,
 						ring_amount,
 					)?;
 				}
 				if kton_amount != 0 {
 					Self::unstake_token::<KtonPool<T>>(
 						&mut l.staked_kton,
-						&mut l.unstaking_kton,
+						&mut u.kton,
 						kton_amount,
 					)?;
 				}
 
-				for d in deposits {
-					Self::unstake_deposit(&who, l, d)?;
+				for d in &deposits {
+					Self::unstake_deposit(&who, l, &mut u, *d)?;
 				}
 
+				<Unstakings<T>>::insert(&who, claimable_at, u);
+
 				DispatchResult::Ok(())
 			})?;
 
-			// TODO: event?
+			Self::refresh_backings(&who);
+
+			Self::deposit_event(Event::Unstaked {
+				staker: who,
+				ring_amount,
+				kton_amount,
+				deposits,
+			});
 
 			Ok(())
 		}
@@ -458,7 +1184,7 @@ pub mod pallet {
 		///
 		/// Re-stake the unstaking assets immediately.
 		#[pallet::call_index(2)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::restake(deposits.len() as _))]
 		pub fn restake(
 			origin: OriginFor<T>,
 			ring_amount: Balance,
@@ -471,39 +1197,59 @@ pub mod pallet {
 				return Ok(());
 			}
 
+			let mut us = <Unstakings<T>>::iter_prefix(&who).collect::<Vec<_>>();
+
+			// Cancel the latest `unstake` first.
+			us.sort_by(|(a, _), (b, _)| b.cmp(a));
+
 			<Ledgers<T>>::try_mutate(&who, |l| {
 				let l = l.as_mut().ok_or(<Error<T>>::NotStaker)?;
 
 				if ring_amount != 0 {
 					Self::restake_token::<RingPool<T>>(
 						&mut l.staked_ring,
-						&mut l.unstaking_ring,
+						us.iter_mut().map(|(_, u)| &mut u.ring),
 						ring_amount,
 					)?;
 				}
 				if kton_amount != 0 {
 					Self::restake_token::<KtonPool<T>>(
 						&mut l.staked_kton,
-						&mut l.unstaking_kton,
+						us.iter_mut().map(|(_, u)| &mut u.kton),
 						kton_amount,
 					)?;
 				}
 
-				for d in deposits {
-					Self::restake_deposit(&who, l, d)?;
+				for d in &deposits {
+					Self::restake_deposit(&who, l, &mut us, *d)?;
 				}
 
 				DispatchResult::Ok(())
 			})?;
 
-			// TODO: event?
+			us.into_iter().for_each(|(t, u)| {
+				if u.is_empty() {
+					<Unstakings<T>>::remove(&who, t);
+				} else {
+					<Unstakings<T>>::insert(&who, t, u);
+				}
+			});
+
+			Self::refresh_backings(&who);
+
+			Self::deposit_event(Event::Restaked {
+				staker: who,
+				ring_amount,
+				kton_amount,
+				deposits,
+			});
 
 			Ok(())
 		}
 
 		/// Claim the stakes from the pallet/contract account.
 		#[pallet::call_index(3)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::claim())]
 		pub fn claim(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -519,14 +1265,52 @@ pub mod pallet {
 		/// Declare the desire to collect.
 		///
 		/// Effects will be felt at the beginning of the next session.
+		///
+		/// For an existing collator, a commission decrease takes effect immediately, while an
+		/// increase will be delayed by the [`Config::CommissionChangeDelay`] sessions.
+		/// A chilled collator's commission is still compared with the one it had before.
 		#[pallet::call_index(4)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::collect())]
 		pub fn collect(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			<Collators<T>>::mutate(&who, |c| *c = Some(commission));
+			if commission > T::MaxCommission::get() {
+				Err(<Error<T>>::CommissionTooHigh)?;
+			}
+			if Self::stakes_of(&who).ring < T::MinCollatorStake::get() {
+				Err(<Error<T>>::InsufficientSelfStake)?;
+			}
+			if !T::KeysRegistry::contains(&who) {
+				Err(<Error<T>>::NoSessionKeys)?;
+			}
 
-			// TODO: event?
+			let current = <Collators<T>>::get(&who).or_else(|| <ChilledCommissions<T>>::take(&who));
+
+			match current {
+				Some(c) if commission > c => {
+					if commission - c > T::MaxCommissionDelta::get() {
+						Err(<Error<T>>::ExceedMaxCommissionDelta)?;
+					}
+
+					let session = <CurrentSession<T>>::get() + T::CommissionChangeDelay::get();
+
+					// Keep the current commission until the increase is applied.
+					<Collators<T>>::insert(&who, c);
+					<PendingCommissions<T>>::insert(&who, (commission, session));
+
+					Self::deposit_event(Event::CommissionChangeScheduled {
+						collator: who,
+						commission,
+						session,
+					});
+				},
+				_ => {
+					<PendingCommissions<T>>::remove(&who);
+					<Collators<T>>::insert(&who, commission);
+
+					Self::deposit_event(Event::CommissionSet { collator: who, commission });
+				},
+			}
 
 			Ok(())
 		}
@@ -535,18 +1319,11 @@ pub mod pallet {
 		///
 		/// Effects will be felt at the beginning of the next session.
 		#[pallet::call_index(5)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::nominate())]
 		pub fn nominate(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			if !<Ledgers<T>>::contains_key(&who) {
-				Err(<Error<T>>::NotStaker)?
-			}
-			if !<Collators<T>>::contains_key(&target) {
-				Err(<Error<T>>::TargetNotCollator)?;
-			}
-
-			<Nominators<T>>::mutate(&who, |n| *n = Some(target));
+			Self::nominate_targets(&who, vec![target], Vec::new())?;
 
 			// TODO: event?
 
@@ -559,17 +1336,269 @@ pub mod pallet {
 		///
 		/// If the target is a collator, its nominators need to re-nominate.
 		#[pallet::call_index(6)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::chill())]
 		pub fn chill(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			<Collators<T>>::remove(&who);
-			<Nominators<T>>::remove(&who);
+			Self::chill_of(&who);
 
 			// TODO: event?
 
 			Ok(())
 		}
+
+		/// Cancel the deferred slashes of the given session.
+		///
+		/// The `slash_indices` are the indices of the [`UnappliedSlashes`] of that session.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_deferred_slash(slash_indices.len() as _))]
+		pub fn cancel_deferred_slash(
+			origin: OriginFor<T>,
+			session: SessionIndex,
+			slash_indices: Vec<u32>,
+		) -> DispatchResult {
+			T::SlashCancelOrigin::ensure_origin(origin)?;
+
+			if slash_indices.is_empty() {
+				Err(<Error<T>>::EmptySlashIndices)?;
+			}
+
+			let mut indices = slash_indices.clone();
+
+			indices.sort_unstable();
+			indices.dedup();
+
+			<UnappliedSlashes<T>>::try_mutate_exists(session, |maybe_ss| {
+				let ss = maybe_ss.as_mut().ok_or(<Error<T>>::InvalidSlashIndex)?;
+
+				if indices.last().map(|i| *i as usize >= ss.len()).unwrap_or_default() {
+					Err(<Error<T>>::InvalidSlashIndex)?;
+				}
+
+				// Remove from the back, so the indices stay valid.
+				indices.into_iter().rev().for_each(|i| {
+					ss.remove(i as _);
+				});
+
+				if ss.is_empty() {
+					*maybe_ss = None;
+				}
+
+				DispatchResult::Ok(())
+			})?;
+
+			Self::deposit_event(Event::SlashCancelled { session, slash_indices });
+
+			Ok(())
+		}
+
+		/// Declare the desire to nominate multiple collators.
+		///
+		/// The nominator's power will be split across the `targets` according to the `weights`,
+		/// which must sum up to 100%. Leave the `weights` empty to split the power evenly.
+		///
+		/// Effects will be felt at the beginning of the next session.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::nominate_multiple(targets.len() as _))]
+		pub fn nominate_multiple(
+			origin: OriginFor<T>,
+			targets: Vec<T::AccountId>,
+			weights: Vec<Perbill>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let nominations = Self::nominate_targets(&who, targets, weights)?;
+
+			Self::deposit_event(Event::Nominated { nominator: who, nominations });
+
+			Ok(())
+		}
+
+		/// Pay out the collator's pending reward of the given session to the stakers.
+		///
+		/// Anyone could call this.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::payout_stakers(T::MaxNominatorsPerCollator::get()))]
+		pub fn payout_stakers(
+			origin: OriginFor<T>,
+			collator: T::AccountId,
+			session: SessionIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let r =
+				<PendingRewards<T>>::take(session, &collator).ok_or(<Error<T>>::NoPendingReward)?;
+			let mut c_payout = r.commission * r.total;
+			let n_payout = r.total - c_payout;
+			// Due to the `payout * percent` there might be some losses.
+			let mut actual_payout = 0;
+
+			for n_exposure in r.exposure.nominators {
+				let n_payout =
+					Perbill::from_rational(n_exposure.value, r.exposure.total) * n_payout;
+
+				if collator == n_exposure.who {
+					// If the collator nominated themselves.
+
+					c_payout += n_payout;
+				} else {
+					actual_payout += Self::pay_reward(&n_exposure.who, n_payout);
+				}
+			}
+
+			actual_payout += Self::pay_reward(&collator, c_payout);
+
+			T::RewardRemainder::on_unbalanced(T::RingCurrency::issue(r.total - actual_payout));
+
+			Ok(())
+		}
+
+		/// Set the destination of the staker's rewards.
+		///
+		/// Effects will be felt at the next payout.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_payee())]
+		pub fn set_payee(
+			origin: OriginFor<T>,
+			destination: RewardDestination<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if !<Ledgers<T>>::contains_key(&who) {
+				Err(<Error<T>>::NotStaker)?;
+			}
+
+			<Payees<T>>::insert(&who, destination.clone());
+
+			Self::deposit_event(Event::PayeeSet { staker: who, destination });
+
+			Ok(())
+		}
+
+		/// Set the ideal number of active collators.
+		///
+		/// Effects will be felt at the next election.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_collator_count())]
+		pub fn set_collator_count(origin: OriginFor<T>, count: u32) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			if (<Invulnerables<T>>::decode_len().unwrap_or_default() as u32) > count {
+				Err(<Error<T>>::ExceedCollatorCount)?;
+			}
+
+			<CollatorCount<T>>::put(count);
+
+			Self::deposit_event(Event::CollatorCountSet { count });
+
+			Ok(())
+		}
+
+		/// Set the collators which will always be elected.
+		///
+		/// The invulnerables which don't `collect` take a 100% commission, which means they
+		/// receive the whole rewards of their sessions.
+		///
+		/// Effects will be felt at the next election.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_invulnerables(invulnerables.len() as _))]
+		pub fn set_invulnerables(
+			origin: OriginFor<T>,
+			invulnerables: Vec<T::AccountId>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let mut invulnerables = invulnerables;
+
+			invulnerables.sort();
+			invulnerables.dedup();
+
+			if invulnerables.len() as u32 > <CollatorCount<T>>::get() {
+				Err(<Error<T>>::ExceedCollatorCount)?;
+			}
+
+			<Invulnerables<T>>::put(&invulnerables);
+
+			Self::deposit_event(Event::InvulnerablesSet { invulnerables });
+
+			Ok(())
+		}
+
+		/// Declare no desire to either collect or nominate on behalf of the staker.
+		///
+		/// Effects will be felt at the beginning of the next session.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::force_chill())]
+		pub fn force_chill(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::chill_of(&who);
+
+			Self::deposit_event(Event::ForceChilled { staker: who });
+
+			Ok(())
+		}
+
+		/// Chill the staker and release all of its stakes immediately.
+		///
+		/// The unstaking assets will be released as well, without waiting for the
+		/// [`Config::MinStakingDuration`].
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::force_unstake(T::MaxDeposits::get()))]
+		pub fn force_unstake(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let l = <Ledgers<T>>::get(&who).ok_or(<Error<T>>::NotStaker)?;
+			let staked_deposit_ring = l
+				.staked_deposits
+				.iter()
+				.try_fold(0, |a, d| T::Deposit::amount(&who, *d).map(|d_amount| a + d_amount))?;
+
+			Self::chill_of(&who);
+			Self::update_pool::<RingPool<T>>(false, l.staked_ring + staked_deposit_ring)?;
+			Self::update_pool::<KtonPool<T>>(false, l.staked_kton)?;
+
+			let mut ring_amount = l.staked_ring;
+			let mut kton_amount = l.staked_kton;
+			let mut deposits = l.staked_deposits.into_inner();
+
+			<Unstakings<T>>::drain_prefix(&who).for_each(|(_, u)| {
+				ring_amount += u.ring;
+				kton_amount += u.kton;
+				deposits.extend(u.deposits);
+			});
+
+			T::Ring::unstake(&who, ring_amount)?;
+			T::Kton::unstake(&who, kton_amount)?;
+
+			for d in &deposits {
+				T::Deposit::unstake(&who, *d)?;
+			}
+
+			<Ledgers<T>>::remove(&who);
+			<Payees<T>>::remove(&who);
+			<ChilledCommissions<T>>::remove(&who);
+			<frame_system::Pallet<T>>::dec_consumers(&who);
+
+			Self::deposit_event(Event::ForceUnstaked {
+				staker: who,
+				ring_amount,
+				kton_amount,
+				deposits,
+			});
+
+			Ok(())
+		}
+
+		/// Lock the RING for `months` and stake the new deposit in a single call.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::lock_and_stake())]
+		pub fn lock_and_stake(origin: OriginFor<T>, amount: Balance, months: u8) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let deposit = T::Deposit::lock(&who, amount, months)?;
+
+			Self::stake(origin, 0, 0, vec![deposit])
+		}
 	}
 	impl<T> Pallet<T>
 	where
@@ -628,7 +1657,7 @@ pub mod pallet {
 
 		fn unstake_token<P>(
 			staked: &mut Balance,
-			unstaking: &mut BoundedVec<(Balance, T::BlockNumber), T::MaxUnstakings>,
+			unstaking: &mut Balance,
 			amount: Balance,
 		) -> DispatchResult
 		where
@@ -637,13 +1666,7 @@ pub mod pallet {
 			*staked = staked
 				.checked_sub(amount)
 				.ok_or("[pallet::staking] `u128` must not be overflowed; qed")?;
-
-			unstaking
-				.try_push((
-					amount,
-					<frame_system::Pallet<T>>::block_number() + T::MinStakingDuration::get(),
-				))
-				.map_err(|_| <Error<T>>::ExceedMaxUnstakings)?;
+			*unstaking += amount;
 
 			Self::update_pool::<P>(false, amount)?;
 
@@ -653,11 +1676,12 @@ pub mod pallet {
 		fn unstake_deposit(
 			who: &T::AccountId,
 			ledger: &mut Ledger<T>,
+			unstaking: &mut Unstaking<T>,
 			deposit: DepositId<T>,
 		) -> DispatchResult {
-			ledger
-				.unstaking_deposits
-				.try_push((
+			unstaking
+				.deposits
+				.try_push(
 					ledger.staked_deposits.remove(
 						ledger
 							.staked_deposits
@@ -665,18 +1689,17 @@ pub mod pallet {
 							.position(|d| d == &deposit)
 							.ok_or(<Error<T>>::DepositNotFound)?,
 					),
-					<frame_system::Pallet<T>>::block_number() + T::MinStakingDuration::get(),
-				))
-				.map_err(|_| <Error<T>>::ExceedMaxUnstakings)?;
+				)
+				.map_err(|_| <Error<T>>::ExceedMaxDeposits)?;
 
 			Self::update_pool::<RingPool<T>>(false, T::Deposit::amount(who, deposit)?)?;
 
 			Ok(())
 		}
 
-		fn restake_token<P>(
+		fn restake_token<'a, P>(
 			staked: &mut Balance,
-			unstaking: &mut BoundedVec<(Balance, T::BlockNumber), T::MaxUnstakings>,
+			unstakings: impl Iterator<Item = &'a mut Balance>,
 			mut amount: Balance,
 		) -> DispatchResult
 		where
@@ -684,26 +1707,15 @@ pub mod pallet {
 		{
 			let mut actual_restake = 0;
 
-			// Cancel the latest `unstake` first.
-			while let Some((u, _)) = unstaking.last_mut() {
-				if let Some(k) = u.checked_sub(amount) {
-					actual_restake += amount;
-					*u = k;
-
-					if k == 0 {
-						unstaking
-							.pop()
-							.ok_or("[pallet::staking] record must exist, due to `last_mut`; qed")?;
-					}
+			for u in unstakings {
+				let r = amount.min(*u);
 
-					break;
-				} else {
-					actual_restake += *u;
-					amount -= *u;
+				*u -= r;
+				amount -= r;
+				actual_restake += r;
 
-					unstaking
-						.pop()
-						.ok_or("[pallet::staking] record must exist, due to `last_mut`; qed")?;
+				if amount == 0 {
+					break;
 				}
 			}
 
@@ -717,21 +1729,20 @@ pub mod pallet {
 		fn restake_deposit(
 			who: &T::AccountId,
 			ledger: &mut Ledger<T>,
+			unstakings: &mut [(T::BlockNumber, Unstaking<T>)],
 			deposit: DepositId<T>,
 		) -> DispatchResult {
 			ledger
 				.staked_deposits
 				.try_push(
-					ledger
-						.unstaking_deposits
-						.remove(
-							ledger
-								.unstaking_deposits
-								.iter()
-								.position(|(d, _)| d == &deposit)
-								.ok_or(<Error<T>>::DepositNotFound)?,
-						)
-						.0,
+					unstakings
+						.iter_mut()
+						.find_map(|(_, u)| {
+							let i = u.deposits.iter().position(|d| d == &deposit)?;
+
+							Some(u.deposits.remove(i))
+						})
+						.ok_or(<Error<T>>::DepositNotFound)?,
 				)
 				.map_err(|_| <Error<T>>::ExceedMaxDeposits)?;
 
@@ -740,58 +1751,194 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Calculate the block at which the assets unstaked at `now` become claimable.
+		///
+		/// The [`Config::MinStakingDuration`] is rounded up to the end of the session.
+		pub fn claimable_at(now: T::BlockNumber) -> T::BlockNumber {
+			let at = now + T::MinStakingDuration::get();
+
+			T::NextSessionRotation::estimate_next_session_rotation(at)
+				.0
+				.filter(|end| end >= &at)
+				.unwrap_or(at)
+		}
+
 		fn claim_unstakings(who: &T::AccountId) -> DispatchResult {
-			<Ledgers<T>>::try_mutate(who, |l| {
-				let l = l.as_mut().ok_or(<Error<T>>::NotStaker)?;
-				let now = <frame_system::Pallet<T>>::block_number();
-				let claim = |u: &mut BoundedVec<_, _>, c: &mut Balance| {
-					u.retain(|(a, t)| {
-						if t <= &now {
-							*c += a;
-
-							false
-						} else {
-							true
-						}
-					});
-				};
-				let mut r_claimed = 0;
+			if !<Ledgers<T>>::contains_key(who) {
+				Err(<Error<T>>::NotStaker)?;
+			}
 
-				claim(&mut l.unstaking_ring, &mut r_claimed);
-				T::Ring::unstake(who, r_claimed)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut r_claimed = 0;
+			let mut k_claimed = 0;
+			let mut d_claimed = Vec::new();
 
-				let mut k_claimed = 0;
+			<Unstakings<T>>::iter_prefix(who)
+				.filter(|(t, _)| t <= &now)
+				.collect::<Vec<_>>()
+				.into_iter()
+				.for_each(|(t, u)| {
+					<Unstakings<T>>::remove(who, t);
 
-				claim(&mut l.unstaking_kton, &mut k_claimed);
-				T::Kton::unstake(who, k_claimed)?;
+					r_claimed += u.ring;
+					k_claimed += u.kton;
+					d_claimed.extend(u.deposits);
+				});
 
-				let mut d_claimed = Vec::new();
+			T::Ring::unstake(who, r_claimed)?;
+			T::Kton::unstake(who, k_claimed)?;
 
-				l.unstaking_deposits.retain(|(d, t)| {
-					if t <= &now {
-						d_claimed.push(*d);
+			for d in d_claimed {
+				T::Deposit::unstake(who, d)?;
+			}
 
-						false
-					} else {
-						true
-					}
-				});
+			Ok(())
+		}
+
+		fn nominate_targets(
+			who: &T::AccountId,
+			targets: Vec<T::AccountId>,
+			weights: Vec<Perbill>,
+		) -> Result<Vec<(T::AccountId, Perbill)>, DispatchError> {
+			if !<Ledgers<T>>::contains_key(who) {
+				Err(<Error<T>>::NotStaker)?
+			}
+			if targets.is_empty() {
+				Err(<Error<T>>::EmptyTargets)?;
+			}
+			if targets.len() > T::MaxNominations::get() as usize {
+				Err(<Error<T>>::ExceedMaxNominations)?;
+			}
 
-				for d in d_claimed {
-					T::Deposit::unstake(who, d)?;
+			for (i, t) in targets.iter().enumerate() {
+				if targets[..i].contains(t) {
+					Err(<Error<T>>::DuplicateTarget)?;
+				}
+				if !<Collators<T>>::contains_key(t) {
+					Err(<Error<T>>::TargetNotCollator)?;
 				}
+			}
 
-				Ok(())
-			})
+			let weights = if weights.is_empty() {
+				Self::even_split(targets.len() as _)
+			} else {
+				if weights.len() != targets.len()
+					|| weights.iter().any(|w| w.is_zero())
+					|| weights.iter().map(|w| w.deconstruct() as u64).sum::<u64>()
+						!= Perbill::one().deconstruct() as u64
+				{
+					Err(<Error<T>>::InvalidNominationWeights)?;
+				}
+
+				weights
+			};
+			let nominations = targets.into_iter().zip(weights).collect::<Vec<_>>();
+			let bounded_nominations =
+				<BoundedVec<_, T::MaxNominations>>::try_from(nominations.clone())
+					.map_err(|_| <Error<T>>::ExceedMaxNominations)?;
+
+			Self::remove_backings(who);
+			<Nominators<T>>::insert(who, bounded_nominations);
+			Self::add_backings(who);
+
+			Ok(nominations)
+		}
+
+		/// Update the backings of the nominator's targets.
+		///
+		/// This must be called after the nominator's stakes have been changed.
+		pub fn refresh_backings(who: &T::AccountId) {
+			Self::remove_backings(who);
+			Self::add_backings(who);
+		}
+
+		fn remove_backings(who: &T::AccountId) {
+			<Nominators<T>>::get(who).unwrap_or_default().into_iter().for_each(|(c, _)| {
+				if let Some(b) = <Backings<T>>::take(&c, who) {
+					<TotalBackings<T>>::mutate(&c, |t| {
+						t.ring = t.ring.saturating_sub(b.ring);
+						t.kton = t.kton.saturating_sub(b.kton);
+					});
+				}
+			});
+		}
+
+		fn add_backings(who: &T::AccountId) {
+			let Some(ts) = <Nominators<T>>::get(who) else {
+				return;
+			};
+			let stakes = Self::stakes_of(who);
+
+			ts.into_iter().for_each(|(c, w)| {
+				let b = Backing { ring: w * stakes.ring, kton: w * stakes.kton };
+
+				<Backings<T>>::insert(&c, who, b);
+				<TotalBackings<T>>::mutate(&c, |t| {
+					t.ring = t.ring.saturating_add(b.ring);
+					t.kton = t.kton.saturating_add(b.kton);
+				});
+			});
+		}
+
+		// Sum up the staked RING, deposits included, and KTON of the given account.
+		fn stakes_of(who: &T::AccountId) -> Backing {
+			<Ledgers<T>>::get(who)
+				.map(|l| Backing {
+					ring: l.staked_ring
+						+ l.staked_deposits
+							.into_iter()
+							// We don't care if the deposit exists here.
+							// It was guaranteed by the `stake`/`unstake`/`restake` functions.
+							.fold(0, |r, d| {
+								r + T::PowerCalculator::deposit2ring(
+									who,
+									d,
+									T::Deposit::amount(who, d).unwrap_or_default(),
+								)
+							}),
+					kton: l.staked_kton,
+				})
+				.unwrap_or_default()
+		}
+
+		// Split 100% into `count` parts, the first part takes the remainder.
+		fn even_split(count: u32) -> Vec<Perbill> {
+			let total = Perbill::one().deconstruct();
+			let part = total / count;
+			let mut weights = vec![Perbill::from_parts(part); count as _];
+
+			if let Some(w) = weights.first_mut() {
+				*w = Perbill::from_parts(part + total % count);
+			}
+
+			weights
+		}
+
+		fn chill_of(who: &T::AccountId) {
+			Self::remove_collator(who);
+			Self::remove_backings(who);
+			<Nominators<T>>::remove(who);
+			<InactiveSessions<T>>::remove(who);
+		}
+
+		// Remove the collator candidate and keep its commission in the [`ChilledCommissions`].
+		fn remove_collator(who: &T::AccountId) {
+			if let Some(c) = <Collators<T>>::take(who) {
+				<ChilledCommissions<T>>::insert(who, c);
+			}
+
+			<PendingCommissions<T>>::remove(who);
 		}
 
 		fn try_clean_ledger_of(who: &T::AccountId) {
 			let _ = <Ledgers<T>>::try_mutate(who, |maybe_l| {
 				let l = maybe_l.as_mut().ok_or(())?;
 
-				if l.is_empty() {
+				if l.is_empty() && <Unstakings<T>>::iter_prefix(who).next().is_none() {
 					*maybe_l = None;
 
+					<Payees<T>>::remove(who);
+					<ChilledCommissions<T>>::remove(who);
 					<frame_system::Pallet<T>>::dec_consumers(who);
 
 					Ok(())
@@ -812,54 +1959,88 @@ pub mod pallet {
 			});
 		}
 
-		// Power is a mixture of RING and KTON.
-		// - `total_ring_power = (amount / total_staked_ring) * HALF_POWER`
-		// - `total_kton_power = (amount / total_staked_kton) * HALF_POWER`
-		fn balance2power<P>(amount: Balance) -> Power
-		where
-			P: frame_support::StorageValue<Balance, Query = Balance>,
-		{
-			(Perquintill::from_rational(amount, P::get().max(1)) * 500_000_000_u128) as _
+		fn backing2power(backing: &Backing) -> Power {
+			T::PowerCalculator::backing2power(backing, <RingPool<T>>::get(), <KtonPool<T>>::get())
 		}
 
 		/// Calculate the power of the given account.
 		pub fn power_of(who: &T::AccountId) -> Power {
-			<Ledgers<T>>::get(who)
-				.map(|l| {
-					Self::balance2power::<RingPool<T>>(
-						l.staked_ring
-							+ l.staked_deposits
-								.into_iter()
-								// We don't care if the deposit exists here.
-								// It was guaranteed by the `stake`/`unstake`/`restake` functions.
-								.fold(0, |r, d| r + T::Deposit::amount(who, d).unwrap_or_default()),
-					) + Self::balance2power::<KtonPool<T>>(l.staked_kton)
-				})
-				.unwrap_or_default()
+			Self::backing2power(&Self::stakes_of(who))
 		}
 
-		// TODO: weight
-		/// Pay the session reward to the stakers.
-		pub fn payout(session_duration: Moment, elapsed_time: Moment) {
-			let unminted = TOTAL_SUPPLY - T::RingCurrency::total_issuance();
+		/// Summarize the ledger of the given account.
+		pub fn ledger_summary_of(who: &T::AccountId) -> Option<LedgerSummary<T::BlockNumber>> {
+			let l = <Ledgers<T>>::get(who)?;
+			let deposit_amount = |d| T::Deposit::amount(who, d).unwrap_or_default();
+			let mut unstaking_ring = Vec::new();
+			let mut unstaking_kton = Vec::new();
 
-			log::info!(
-				"\
-					[pallet::staking] making a payout for: \
-					`unminted = {unminted}`, \
-					`session_duration = {session_duration}`, \
-					`elapsed_time = {elapsed_time}`\
-				"
-			);
+			<Unstakings<T>>::iter_prefix(who).for_each(|(t, u)| {
+				let r = u.deposits.into_iter().fold(u.ring, |r, d| r + deposit_amount(d));
+
+				if r != 0 {
+					unstaking_ring.push((r, t));
+				}
+				if u.kton != 0 {
+					unstaking_kton.push((u.kton, t));
+				}
+			});
+			unstaking_ring.sort_by_key(|(_, t)| *t);
+			unstaking_kton.sort_by_key(|(_, t)| *t);
+
+			Some(LedgerSummary {
+				staked_ring: l.staked_ring
+					+ l.staked_deposits.into_iter().fold(0, |r, d| r + deposit_amount(d)),
+				staked_kton: l.staked_kton,
+				unstaking_ring,
+				unstaking_kton,
+			})
+		}
 
+		/// Move the unstaking assets of a [`LegacyLedger`] into the [`Unstakings`].
+		///
+		/// Return the remaining [`Ledger`], which should be put into the [`Ledgers`] by the caller.
+		pub fn split_legacy_ledger(who: &T::AccountId, legacy: LegacyLedger<T>) -> Ledger<T> {
+			let LegacyLedger {
+				staked_ring,
+				staked_kton,
+				staked_deposits,
+				unstaking_ring,
+				unstaking_kton,
+				unstaking_deposits,
+			} = legacy;
+
+			unstaking_ring.into_iter().filter(|(r, _)| *r != 0).for_each(|(r, t)| {
+				<Unstakings<T>>::mutate(who, t, |u| {
+					u.get_or_insert_with(Default::default).ring += r
+				})
+			});
+			unstaking_kton.into_iter().filter(|(k, _)| *k != 0).for_each(|(k, t)| {
+				<Unstakings<T>>::mutate(who, t, |u| {
+					u.get_or_insert_with(Default::default).kton += k
+				})
+			});
+			unstaking_deposits.into_iter().for_each(|(d, t)| {
+				<Unstakings<T>>::mutate(who, t, |u| {
+					if u.get_or_insert_with(Default::default).deposits.try_push(d).is_err() {
+						log::error!("[pallet::staking] failed to migrate {who:?}'s deposit({d:?})");
+					}
+				})
+			});
+
+			Ledger { staked_ring, staked_kton, staked_deposits }
+		}
+
+		// Calculate the inflation of a session.
+		fn inflation_of(session_duration: Moment, elapsed_time: Moment) -> Option<Balance> {
 			let Some(inflation) = dc_inflation::in_period(
-				unminted,
+				TOTAL_SUPPLY - T::RingCurrency::total_issuance(),
 				session_duration,
 				elapsed_time,
 			) else {
 				log::error!("[pallet::staking] failed to calculate the inflation");
 
-				return;
+				return None;
 			};
 
 			// TODO: add some tests in the core inflation,
@@ -867,102 +2048,564 @@ pub mod pallet {
 			if inflation > 1_000_000 * UNIT {
 				log::error!("[pallet::staking] it's impossible to mint over 1 million RING within a session according to current reward curve");
 
-				return;
+				return None;
 			}
 
+			Some(inflation)
+		}
+
+		/// Summarize the exposure of the given collator in the active session.
+		pub fn exposure_summary_of(
+			collator: &T::AccountId,
+		) -> Option<ExposureSummary<T::AccountId>> {
+			let e = <HistoricalExposures<T>>::get(<CurrentSession<T>>::get(), collator)?;
+
+			Some(ExposureSummary { total: e.total, nominators: e.nominators.into_inner() })
+		}
+
+		/// Summarize the archived exposure of the given collator at the given session.
+		pub fn historical_exposure_summary_of(
+			session: SessionIndex,
+			collator: &T::AccountId,
+		) -> Option<ExposureSummary<T::AccountId>> {
+			let e = <HistoricalExposures<T>>::get(session, collator)?;
+
+			Some(ExposureSummary { total: e.total, nominators: e.nominators.into_inner() })
+		}
+
+		/// Collect the archived reward points of the collators at the given session.
+		pub fn historical_reward_points(session: SessionIndex) -> Vec<(T::AccountId, RewardPoint)> {
+			<HistoricalRewardPoints<T>>::iter_prefix(session).collect()
+		}
+
+		/// Estimate the payout of the given collator for the active session, nominators' part
+		/// included.
+		///
+		/// The estimation is based on the reward points and the time elapsed so far.
+		pub fn estimated_session_payout_of(collator: &T::AccountId) -> Balance {
+			let session_duration =
+				T::UnixTime::now().as_millis().saturating_sub(<SessionStartTime<T>>::get());
+			let elapsed_time = <ElapsedTime<T>>::get() + session_duration;
+			let Some(inflation) = Self::inflation_of(session_duration, elapsed_time) else {
+				return 0;
+			};
+			let (total_points, reward_map) = <RewardPoints<T>>::get();
+
+			reward_map
+				.get(collator)
+				.map(|p| {
+					Perbill::from_rational(*p, total_points)
+						* (T::PayoutFraction::get() * inflation)
+				})
+				.unwrap_or_default()
+		}
+
+		/// Record the session reward of the collators.
+		///
+		/// The rewards will be paid out through the [`Call::payout_stakers`].
+		///
+		/// The weight will be registered to the current block.
+		pub fn payout(session: SessionIndex, session_duration: Moment, elapsed_time: Moment) {
+			log::info!(
+				"\
+					[pallet::staking] making a payout for: \
+					`session = {session}`, \
+					`session_duration = {session_duration}`, \
+					`elapsed_time = {elapsed_time}`\
+				"
+			);
+
+			let Some(inflation) = Self::inflation_of(session_duration, elapsed_time) else {
+				return;
+			};
 			let payout = T::PayoutFraction::get() * inflation;
 			let (total_points, reward_map) = <RewardPoints<T>>::get();
-			// Due to the `payout * percent` there might be some losses.
-			let mut actual_payout = 0;
+			let collator_count = reward_map.len() as _;
+			let mut recorded_payout = 0;
 
 			for (c, p) in reward_map {
-				let Some(commission) = <Collators<T>>::get(&c) else {
-					log::error!("[pallet::staking] collator({c:?}) must be found; qed");
-
-					continue;
-				};
-				let c_total_payout = Perbill::from_rational(p, total_points) * payout;
-				let mut c_payout = commission * c_total_payout;
-				let n_payout = c_total_payout - c_payout;
-				let Some(c_exposure) = <Exposures<T>>::get(&c) else {
+				let Some(exposure) = <HistoricalExposures<T>>::get(session, &c) else {
 					log::error!("[pallet::staking] exposure({c:?}) must be found; qed");
 
 					continue;
 				};
+				let total = Perbill::from_rational(p, total_points) * payout;
+
+				recorded_payout += total;
+
+				<PendingRewards<T>>::insert(
+					session,
+					&c,
+					PendingReward { commission: exposure.commission, total, exposure },
+				);
 
-				for n_exposure in c_exposure.nominators {
-					let n_payout =
-						Perbill::from_rational(n_exposure.value, c_exposure.total) * n_payout;
+				Self::deposit_event(Event::RewardRecorded {
+					collator: c,
+					session,
+					ring_amount: total,
+				});
+			}
+
+			<SessionSummaries<T>>::insert(
+				session,
+				SessionSummary { inflation, payout: recorded_payout, reward_points: total_points },
+			);
 
-					if c == n_exposure.who {
-						// If the collator nominated themselves.
+			// The recorded rewards will be issued while paying out.
+			T::RewardRemainder::on_unbalanced(T::RingCurrency::issue(inflation - recorded_payout));
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::payout(collator_count),
+				DispatchClass::Mandatory,
+			);
+		}
 
-						c_payout += n_payout;
-					} else if T::RingCurrency::deposit_into_existing(&n_exposure.who, n_payout)
+		// Issue the reward to the staker's reward destination.
+		//
+		// The reaped account will be recreated, if the reward is not less than the existential
+		// deposit.
+		// If the reward fails to be staked, it will be left as free RING.
+		//
+		// Return the actual paid amount.
+		fn pay_reward(who: &T::AccountId, amount: Balance) -> Balance {
+			let paid = match <Payees<T>>::get(who) {
+				RewardDestination::Account(a) => T::RingCurrency::deposit_creating(&a, amount),
+				RewardDestination::Staked => {
+					let paid = T::RingCurrency::deposit_creating(who, amount);
+
+					if paid.peek() != 0
+						&& <Ledgers<T>>::try_mutate(who, |l| {
+							let l = l.as_mut().ok_or(<Error<T>>::NotStaker)?;
+
+							Self::stake_token::<T::Ring, RingPool<T>>(
+								who,
+								&mut l.staked_ring,
+								paid.peek(),
+							)
+						})
 						.is_ok()
 					{
-						actual_payout += n_payout;
-
-						Self::deposit_event(Event::Payout {
-							staker: n_exposure.who,
-							ring_amount: n_payout,
-						});
+						Self::refresh_backings(who);
 					}
-				}
 
-				if T::RingCurrency::deposit_into_existing(&c, c_payout).is_ok() {
-					actual_payout += c_payout;
+					paid
+				},
+				RewardDestination::Free => T::RingCurrency::deposit_creating(who, amount),
+			}
+			.peek();
+
+			if paid != 0 {
+				Self::deposit_event(Event::Payout { staker: who.to_owned(), ring_amount: paid });
+			}
+
+			paid
+		}
+
+		/// Expire the pending rewards which are out of the [`Config::HistoryDepth`].
+		///
+		/// The expired rewards will go to the [`Config::RewardRemainder`].
+		///
+		/// The weight will be registered to the current block.
+		pub fn expire_rewards(session: SessionIndex) {
+			let Some(expired_session) = session.checked_sub(T::HistoryDepth::get()) else {
+				return;
+			};
+			let mut collator_count = 0;
+			let ring_amount =
+				<PendingRewards<T>>::drain_prefix(expired_session).fold(0, |a, (_, r)| {
+					collator_count += 1;
+
+					a + r.total
+				});
+
+			if ring_amount != 0 {
+				T::RewardRemainder::on_unbalanced(T::RingCurrency::issue(ring_amount));
+
+				Self::deposit_event(Event::RewardExpired { session: expired_session, ring_amount });
+			}
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::expire_rewards(collator_count),
+				DispatchClass::Mandatory,
+			);
+		}
+
+		/// Prune the archived session data which is out of the [`Config::HistoryDepth`].
+		///
+		/// The weight will be registered to the current block.
+		pub fn prune_history(session: SessionIndex) {
+			let Some(expired_session) = session.checked_sub(T::HistoryDepth::get()) else {
+				return;
+			};
+
+			<SessionSummaries<T>>::remove(expired_session);
+
+			let collator_count =
+				<HistoricalRewardPoints<T>>::clear_prefix(expired_session, u32::MAX, None).unique;
+			let exposure_count =
+				<HistoricalExposures<T>>::clear_prefix(expired_session, u32::MAX, None).unique;
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::prune_history(collator_count.max(exposure_count)),
+				DispatchClass::Mandatory,
+			);
+		}
+
+		/// Apply the commission changes which are due at the given session.
+		///
+		/// The weight will be registered to the current block.
+		pub fn apply_commission_changes(session: SessionIndex) {
+			let mut change_count = 0;
+			let due = <PendingCommissions<T>>::iter()
+				.filter(|(_, (_, s))| {
+					change_count += 1;
+
+					*s <= session
+				})
+				.collect::<Vec<_>>();
 
-					Self::deposit_event(Event::Payout { staker: c, ring_amount: c_payout });
+			due.into_iter().for_each(|(c, (commission, _))| {
+				<PendingCommissions<T>>::remove(&c);
+				<Collators<T>>::insert(&c, commission);
+
+				Self::deposit_event(Event::CommissionChangeApplied { collator: c, commission });
+			});
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::apply_commission_changes(change_count),
+				DispatchClass::Mandatory,
+			);
+		}
+
+		/// Apply the slashes which are due at the given session.
+		///
+		/// The overdue slashes will be applied as well.
+		///
+		/// The weight will be registered to the current block.
+		pub fn apply_unapplied_slashes(session: SessionIndex) {
+			let mut session_count = 0;
+			let due = <UnappliedSlashes<T>>::iter_keys()
+				.filter(|s| {
+					session_count += 1;
+
+					s <= &session
+				})
+				.collect::<Vec<_>>();
+			let mut weight = T::DbWeight::get().reads(session_count);
+
+			due.into_iter().for_each(|s| {
+				<UnappliedSlashes<T>>::take(s).into_iter().for_each(|slash| {
+					weight = weight.saturating_add(Self::apply_slash(slash));
+				});
+			});
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				weight,
+				DispatchClass::Mandatory,
+			);
+		}
+
+		// Return the weight of the slash.
+		fn apply_slash(slash: UnappliedSlash<T>) -> Weight {
+			let weight = T::WeightInfo::apply_slash(slash.nominators.len() as _);
+
+			Self::slash_staker(&slash.collator, slash.fraction);
+
+			slash
+				.nominators
+				.iter()
+				// The collator has been slashed above.
+				.filter(|(n, _)| n != &slash.collator)
+				.for_each(|(n, s)| Self::slash_staker(n, slash.fraction * *s));
+
+			weight
+		}
+
+		// Calculate the share of the nominator's stakes which backs the collator.
+		//
+		// Fall back to the share of the exposed power, if the nominator has stopped nominating the
+		// collator since the election.
+		fn backing_share_of(
+			nominator: &T::AccountId,
+			collator: &T::AccountId,
+			exposed: Power,
+		) -> Perbill {
+			<Nominators<T>>::get(nominator)
+				.and_then(|ns| ns.into_iter().find(|(c, _)| c == collator).map(|(_, w)| w))
+				.unwrap_or_else(|| Perbill::from_rational(exposed, Self::power_of(nominator)))
+		}
+
+		fn slash_staker(who: &T::AccountId, fraction: Perbill) {
+			let Some(mut l) = <Ledgers<T>>::get(who) else {
+				return;
+			};
+			let mut ring_amount = 0;
+			let mut kton_amount = 0;
+
+			// Slash the staked items, the pools need to be updated.
+			match T::Ring::slash(who, l.staked_ring, fraction) {
+				Ok(r) => {
+					l.staked_ring = l.staked_ring.saturating_sub(r);
+					ring_amount += r;
+
+					let _ = Self::update_pool::<RingPool<T>>(false, r);
+				},
+				Err(e) => log::error!("[pallet::staking] failed to slash {who:?}'s RING: {e:?}"),
+			}
+			match T::Kton::slash(who, l.staked_kton, fraction) {
+				Ok(k) => {
+					l.staked_kton = l.staked_kton.saturating_sub(k);
+					kton_amount += k;
+
+					let _ = Self::update_pool::<KtonPool<T>>(false, k);
+				},
+				Err(e) => log::error!("[pallet::staking] failed to slash {who:?}'s KTON: {e:?}"),
+			}
+			for d in l.staked_deposits.iter() {
+				match T::Deposit::slash(who, *d, fraction) {
+					Ok(r) => {
+						ring_amount += r;
+
+						let _ = Self::update_pool::<RingPool<T>>(false, r);
+					},
+					Err(e) => log::error!(
+						"[pallet::staking] failed to slash {who:?}'s deposit({d:?}): {e:?}"
+					),
 				}
 			}
 
-			T::RewardRemainder::on_unbalanced(T::RingCurrency::issue(inflation - actual_payout));
+			// Slash the unstaking items, they have already been removed from the pools.
+			for (t, mut u) in <Unstakings<T>>::iter_prefix(who).collect::<Vec<_>>() {
+				if let Ok(r) = T::Ring::slash(who, u.ring, fraction) {
+					u.ring = u.ring.saturating_sub(r);
+					ring_amount += r;
+				}
+				if let Ok(k) = T::Kton::slash(who, u.kton, fraction) {
+					u.kton = u.kton.saturating_sub(k);
+					kton_amount += k;
+				}
+				for d in u.deposits.iter() {
+					if let Ok(r) = T::Deposit::slash(who, *d, fraction) {
+						ring_amount += r;
+					}
+				}
+
+				<Unstakings<T>>::insert(who, t, u);
+			}
+
+			<Ledgers<T>>::insert(who, l);
+			Self::refresh_backings(who);
+
+			Self::deposit_event(Event::Slashed {
+				staker: who.to_owned(),
+				ring_amount,
+				kton_amount,
+			});
 		}
 
 		/// Clean the old session data.
-		pub fn clean_old_session() {
-			<RewardPoints<T>>::kill();
-			#[allow(deprecated)]
-			<Exposures<T>>::remove_all(None);
+		///
+		/// The reward points will be moved to the [`HistoricalRewardPoints`].
+		/// The exposures have been archived at the election already.
+		///
+		/// The weight will be registered to the current block.
+		pub fn clean_old_session(session: SessionIndex) {
+			let (_, reward_map) = <RewardPoints<T>>::take();
+			let collator_count = reward_map.len() as _;
+
+			reward_map.into_iter().for_each(|(c, p)| {
+				<HistoricalRewardPoints<T>>::insert(session, c, p);
+			});
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::clean_old_session(collator_count),
+				DispatchClass::Mandatory,
+			);
 		}
 
-		/// Elect the new collators.
+		/// Chill the collators which have been inactive for [`Config::MaxInactiveSessions`]
+		/// consecutive sessions.
 		///
-		/// This should only be called by the [`pallet_session::SessionManager::new_session`].
-		pub fn elect() -> Vec<T::AccountId> {
-			let mut collators = <Collators<T>>::iter_keys()
-				.map(|c| {
-					let mut t_power = 0;
-					let i_exposures = <Nominators<T>>::iter()
-						.filter_map(|(n, c_)| {
-							if c_ == c {
-								let n_power = Self::power_of(&n);
-
-								t_power += n_power;
-
-								Some(IndividualExposure { who: n, value: n_power })
-							} else {
-								None
-							}
-						})
-						.collect();
+		/// A collator is inactive in a session if it authors fewer blocks than the
+		/// [`Config::MinAuthoredBlocks`]. The [`Invulnerables`] are never chilled.
+		///
+		/// Only the collators which are active in the given session, see the
+		/// [`HistoricalExposures`], are checked. The [`Exposures`] belong to the upcoming session
+		/// already.
+		///
+		/// The chilled collators are reported as the offenders of the given session, with the
+		/// [`Config::InactivitySlashFraction`].
+		///
+		/// The weight will be registered to the current block.
+		pub fn chill_inactive_collators(session: SessionIndex) {
+			let min_blocks = T::MinAuthoredBlocks::get();
+			let max_sessions = T::MaxInactiveSessions::get();
+			let invulnerables = <Invulnerables<T>>::get();
+			let authored_blocks = <AuthoredBlocks<T>>::drain().collect::<BTreeMap<_, _>>();
+			let mut collator_count = 0;
+			let mut offenders = Vec::new();
+
+			<HistoricalExposures<T>>::iter_key_prefix(session)
+				.filter(|c| !invulnerables.contains(c))
+				.for_each(|c| {
+					collator_count += 1;
+
+					if authored_blocks.get(&c).copied().unwrap_or_default() >= min_blocks {
+						<InactiveSessions<T>>::remove(&c);
+
+						return;
+					}
 
-					((c, Exposure { total: t_power, nominators: i_exposures }), t_power)
+					let inactive_sessions = <InactiveSessions<T>>::mutate(&c, |s| {
+						*s += 1;
+
+						*s
+					});
+
+					if inactive_sessions >= max_sessions {
+						Self::chill_of(&c);
+						Self::deposit_event(Event::InactiveCollatorChilled {
+							collator: c.clone(),
+							inactive_sessions,
+						});
+
+						offenders.push(OffenceDetails { offender: c, reporters: Vec::new() });
+					}
+				});
+
+			let fraction = T::InactivitySlashFraction::get();
+			let slash_weight = if offenders.is_empty() || fraction == Perbill::zero() {
+				Weight::zero()
+			} else {
+				<Self as OnOffenceHandler<_, _, _>>::on_offence(
+					&offenders,
+					&vec![fraction; offenders.len()],
+					session,
+					DisableStrategy::Never,
+				)
+			};
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::chill_inactive_collators(collator_count)
+					.saturating_add(slash_weight),
+				DispatchClass::Mandatory,
+			);
+		}
+
+		/// Drop the collator candidates whose self-stakes are below the
+		/// [`Config::MinCollatorStake`].
+		///
+		/// The weight will be registered to the current block.
+		pub fn drop_underbonded_collators() {
+			let min_stake = T::MinCollatorStake::get();
+			let mut candidate_count = 0;
+			let dropped = <Collators<T>>::iter_keys()
+				.filter(|c| {
+					candidate_count += 1;
+
+					Self::stakes_of(c).ring < min_stake
 				})
 				.collect::<Vec<_>>();
 
-			collators.sort_by(|(_, a), (_, b)| b.cmp(a));
+			dropped.into_iter().for_each(|c| {
+				Self::remove_collator(&c);
 
-			collators
+				Self::deposit_event(Event::CollatorDropped { collator: c });
+			});
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::drop_underbonded_collators(candidate_count),
+				DispatchClass::Mandatory,
+			);
+		}
+
+		/// Elect the new collators for the given session.
+		///
+		/// Their exposures replace the [`Exposures`] and get archived in the
+		/// [`HistoricalExposures`] of that session.
+		///
+		/// This should only be called by the [`pallet_session::SessionManager::new_session`].
+		///
+		/// The weight will be registered to the current block.
+		pub fn elect(session: SessionIndex) -> Vec<T::AccountId> {
+			let invulnerables = <Invulnerables<T>>::get();
+			let ranking = Self::collator_ranking();
+			let candidate_count = (invulnerables.len() + ranking.len()) as _;
+			let mut nomination_count = 0;
+
+			// The exposures of the previous election have been archived.
+			let _ = <Exposures<T>>::clear(u32::MAX, None);
+
+			let collators = invulnerables
+				.clone()
 				.into_iter()
+				.chain(ranking.into_iter().map(|(c, _)| c).filter(|c| !invulnerables.contains(c)))
 				.take(<CollatorCount<T>>::get() as _)
-				.map(|((c, e), _)| {
+				.map(|c| {
+					let (e, evicted) = Self::assemble_exposure_of(&c);
+
+					nomination_count += (e.nominators.len() + evicted.len()) as u32;
+
+					evicted.into_iter().for_each(|n| {
+						Self::deposit_event(Event::NominatorEvicted {
+							nominator: n,
+							collator: c.clone(),
+						})
+					});
+					<HistoricalExposures<T>>::insert(session, &c, &e);
 					<Exposures<T>>::insert(&c, e);
 
 					c
 				})
-				.collect()
+				.collect();
+
+			<frame_system::Pallet<T>>::register_extra_weight_unchecked(
+				T::WeightInfo::elect(candidate_count, nomination_count),
+				DispatchClass::Mandatory,
+			);
+
+			collators
+		}
+
+		/// Rank the collator candidates by their total backing power, in descending order.
+		///
+		/// The evicted nominators are counted here, since the ranking is based on the cached
+		/// [`TotalBackings`].
+		pub fn collator_ranking() -> Vec<(T::AccountId, Power)> {
+			let mut collators = <Collators<T>>::iter_keys()
+				.map(|c| {
+					let p = Self::backing2power(&<TotalBackings<T>>::get(&c));
+
+					(c, p)
+				})
+				.collect::<Vec<_>>();
+
+			collators.sort_by(|(a_c, a_p), (b_c, b_p)| b_p.cmp(a_p).then_with(|| a_c.cmp(b_c)));
+
+			collators
+		}
+
+		// Assemble the exposure of the collator from the [`Backings`].
+		//
+		// Only the top nominators will be kept, the rest will be returned as the evicted.
+		fn assemble_exposure_of(collator: &T::AccountId) -> (Exposure<T>, Vec<T::AccountId>) {
+			let mut ns = <Backings<T>>::iter_prefix(collator)
+				.map(|(n, b)| IndividualExposure { who: n, value: Self::backing2power(&b) })
+				.collect::<Vec<_>>();
+
+			ns.sort_by(|a, b| b.value.cmp(&a.value));
+
+			let evicted = ns
+				.split_off((T::MaxNominatorsPerCollator::get() as usize).min(ns.len()))
+				.into_iter()
+				.map(|n| n.who)
+				.collect();
+			let total = ns.iter().map(|n| n.value).sum();
+			// Only the invulnerables could be elected without collecting, they take the whole
+			// reward, since they are not open for the nominations.
+			let commission = <Collators<T>>::get(collator).unwrap_or(Perbill::one());
+
+			(Exposure { commission, total, nominators: BoundedVec::truncate_from(ns) }, evicted)
 		}
 	}
 }
@@ -977,6 +2620,8 @@ where
 	T: Config + pallet_authorship::Config + pallet_session::Config,
 {
 	fn note_author(author: T::AccountId) {
+		<AuthoredBlocks<T>>::mutate(&author, |b| *b += 1);
+
 		Self::reward_by_ids(&[(author, 20)])
 	}
 
@@ -1001,16 +2646,40 @@ where
 			<frame_system::Pallet<T>>::block_number(),
 		);
 
-		let collators = Self::elect();
+		Self::drop_underbonded_collators();
+
+		let collators = Self::elect(index);
+
+		if collators.is_empty() {
+			log::error!("[pallet::staking] no collator has been elected, keep the current ones");
+
+			// The current collators will stay, so do their exposures.
+			if let Some(previous) = index.checked_sub(1) {
+				<HistoricalExposures<T>>::iter_prefix(previous)
+					.collect::<Vec<_>>()
+					.into_iter()
+					.for_each(|(c, e)| {
+						<HistoricalExposures<T>>::insert(index, &c, &e);
+						<Exposures<T>>::insert(c, e);
+					});
+			}
+
+			return None;
+		}
 
 		Self::deposit_event(Event::Elected { collators: collators.clone() });
 
 		Some(collators)
 	}
 
-	fn start_session(_: u32) {}
+	fn start_session(index: u32) {
+		<CurrentSession<T>>::put(index);
+
+		Self::apply_commission_changes(index);
+		Self::apply_unapplied_slashes(index);
+	}
 
-	fn end_session(_: u32) {
+	fn end_session(index: u32) {
 		let now = T::UnixTime::now().as_millis();
 		let session_duration = now - <SessionStartTime<T>>::get();
 		let elapsed_time = <ElapsedTime<T>>::mutate(|t| {
@@ -1021,8 +2690,78 @@ where
 
 		<SessionStartTime<T>>::put(now);
 
-		Self::payout(session_duration, elapsed_time);
-		Self::clean_old_session();
+		Self::payout(index, session_duration, elapsed_time);
+		Self::expire_rewards(index);
+		Self::prune_history(index);
+		Self::chill_inactive_collators(index);
+		Self::clean_old_session(index);
+	}
+}
+
+// Handle the offences reported through the `pallet_offences`.
+//
+// The offenders must be the collators, their nominators are taken from the exposures of the
+// offence's session.
+impl<T> OnOffenceHandler<T::AccountId, T::AccountId, Weight> for Pallet<T>
+where
+	T: Config,
+{
+	fn on_offence(
+		offenders: &[OffenceDetails<T::AccountId, T::AccountId>],
+		slash_fraction: &[Perbill],
+		session: SessionIndex,
+		_: DisableStrategy,
+	) -> Weight {
+		let max_fraction = T::MaxSlashFraction::get();
+		let defer_duration = T::SlashDeferDuration::get();
+		let mut weight = Weight::zero();
+
+		for (details, fraction) in offenders.iter().zip(slash_fraction) {
+			let collator = details.offender.to_owned();
+			let fraction = (*fraction).min(max_fraction);
+			let nominators = <HistoricalExposures<T>>::get(session, &collator)
+				.map(|e| {
+					e.nominators
+						.into_iter()
+						.map(|n| {
+							let share = Self::backing_share_of(&n.who, &collator, n.value);
+
+							(n.who, share)
+						})
+						.collect::<Vec<_>>()
+				})
+				.unwrap_or_default();
+
+			weight = weight.saturating_add(T::WeightInfo::on_offence(nominators.len() as _));
+
+			// The nominators come from a bounded exposure, this never truncates.
+			let slash = UnappliedSlash {
+				collator: collator.clone(),
+				fraction,
+				nominators: BoundedVec::truncate_from(nominators),
+			};
+
+			if defer_duration == 0 {
+				weight = weight.saturating_add(Self::apply_slash(slash));
+			} else {
+				let apply_at = session.saturating_add(defer_duration);
+
+				<UnappliedSlashes<T>>::append(apply_at, slash);
+
+				Self::deposit_event(Event::SlashDeferred { collator, fraction, session: apply_at });
+			}
+		}
+
+		weight
+	}
+}
+
+impl<T> RefreshBackings<T::AccountId> for Pallet<T>
+where
+	T: Config,
+{
+	fn refresh_backings(who: &T::AccountId) {
+		<Pallet<T>>::refresh_backings(who);
 	}
 }
 
@@ -0,0 +1,68 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition of the staking pallet.
+//!
+//! Provide the computed staking data, so that the clients don't have to re-implement the
+//! power/reward calculations off-chain.
+
+// crates.io
+use codec::Codec;
+// darwinia
+use crate::{ExposureSummary, LedgerSummary, Power, RewardPoint, SessionSummary};
+use dc_types::Balance;
+// substrate
+use sp_staking::SessionIndex;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the staking data.
+	pub trait StakingApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Calculate the power of the given account.
+		fn power_of(who: AccountId) -> Power;
+
+		/// Summarize the ledger of the given account.
+		fn ledger_summary_of(who: AccountId) -> Option<LedgerSummary<BlockNumber>>;
+
+		/// Get the exposure of the given collator in the active session.
+		fn exposure_of(collator: AccountId) -> Option<ExposureSummary<AccountId>>;
+
+		/// Estimate the payout of the given collator for the active session, nominators' part
+		/// included.
+		fn estimated_session_payout_of(collator: AccountId) -> Balance;
+
+		/// Rank the collator candidates by their total power, in descending order.
+		fn collator_ranking() -> Vec<(AccountId, Power)>;
+
+		/// Get the archived exposure of the given collator at the given session.
+		fn historical_exposure_of(
+			session: SessionIndex,
+			collator: AccountId,
+		) -> Option<ExposureSummary<AccountId>>;
+
+		/// Get the archived reward points of the collators at the given session.
+		fn historical_reward_points(session: SessionIndex) -> Vec<(AccountId, RewardPoint)>;
+
+		/// Get the archived payout figures of the given session.
+		fn session_summary_of(session: SessionIndex) -> Option<SessionSummary>;
+	}
+}
@@ -1,4 +1,1427 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_staking`.
+//!
+//! THESE WEIGHTS ARE NOT GENERATED BY THE SUBSTRATE BENCHMARK CLI.
+//! They are hand-written placeholders, estimated from the storage accesses of the benchmarks.
+//! Regenerate this file with the command below before going to production.
+
+// Regenerate with:
+// target/release/darwinia
+// benchmark
+// pallet
+// --header
+// .maintain/license-header
+// --template
+// .maintain/pallet-weight-template.hbs
+// --execution
+// wasm
+// --heap-pages
+// 4096
+// --chain
+// pangolin-local
+// --output
+// pallet/staking/src/weights.rs
+// --extrinsic
+// *
+// --pallet
+// darwinia-staking
+// --steps
+// 50
+// --repeat
+// 20
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(missing_docs)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for darwinia_staking.
+pub trait WeightInfo {
+	fn stake(x: u32, ) -> Weight;
+	fn unstake(x: u32, ) -> Weight;
+	fn restake(x: u32, ) -> Weight;
+	fn claim() -> Weight;
+	fn collect() -> Weight;
+	fn nominate() -> Weight;
+	fn nominate_multiple(x: u32, ) -> Weight;
+	fn chill() -> Weight;
+	fn cancel_deferred_slash(x: u32, ) -> Weight;
+	fn payout(x: u32, ) -> Weight;
+	fn payout_stakers(x: u32, ) -> Weight;
+	fn set_payee() -> Weight;
+	fn set_collator_count() -> Weight;
+	fn set_invulnerables(x: u32, ) -> Weight;
+	fn force_chill() -> Weight;
+	fn force_unstake(x: u32, ) -> Weight;
+	fn lock_and_stake() -> Weight;
+	fn expire_rewards(x: u32, ) -> Weight;
+	fn prune_history(x: u32, ) -> Weight;
+	fn chill_inactive_collators(x: u32, ) -> Weight;
+	fn clean_old_session(x: u32, ) -> Weight;
+	fn apply_commission_changes(x: u32, ) -> Weight;
+	fn drop_underbonded_collators(x: u32, ) -> Weight;
+	fn elect(x: u32, y: u32, ) -> Weight;
+	fn on_offence(x: u32, ) -> Weight;
+	fn apply_slash(x: u32, ) -> Weight;
+}
+
+/// Weights for darwinia_staking using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn stake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2315`
+		//  Estimated: `104855`
+		// Minimum execution time: 185_263 nanoseconds.
+		Weight::from_ref_time(201_373_698)
+			.saturating_add(Weight::from_proof_size(104855))
+			// Standard Error: 7_003
+			.saturating_add(Weight::from_ref_time(6_118_622).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(41_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:1 w:1)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn unstake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3012`
+		//  Estimated: `94482`
+		// Minimum execution time: 140_876 nanoseconds.
+		Weight::from_ref_time(153_127_957)
+			.saturating_add(Weight::from_proof_size(94482))
+			// Standard Error: 34_752
+			.saturating_add(Weight::from_ref_time(2_963_632).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(36_u64))
+	}
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn restake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3324`
+		//  Estimated: `135890`
+		// Minimum execution time: 218_514 nanoseconds.
+		Weight::from_ref_time(237_516_036)
+			.saturating_add(Weight::from_proof_size(135890))
+			// Standard Error: 25_197
+			.saturating_add(Weight::from_ref_time(3_151_646).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(54_u64))
+			.saturating_add(T::DbWeight::get().writes(51_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4127`
+		//  Estimated: `63373`
+		// Minimum execution time: 246_882 nanoseconds.
+		Weight::from_ref_time(268_351_448)
+			.saturating_add(Weight::from_proof_size(63373))
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(23_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Session NextKeys (r:1 w:0)
+	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Collators (r:1 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking CurrentSession (r:1 w:0)
+	/// Proof: DarwiniaStaking CurrentSession (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingCommissions (r:0 w:1)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking ChilledCommissions (r:1 w:1)
+	/// Proof: DarwiniaStaking ChilledCommissions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn collect() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1919`
+		//  Estimated: `11929`
+		// Minimum execution time: 35_232 nanoseconds.
+		Weight::from_ref_time(38_296_540)
+			.saturating_add(Weight::from_proof_size(11929))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:1 w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:17)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:17 w:17)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn nominate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1972`
+		//  Estimated: `92074`
+		// Minimum execution time: 147_767 nanoseconds.
+		Weight::from_ref_time(160_617_808)
+			.saturating_add(Weight::from_proof_size(92074))
+			.saturating_add(T::DbWeight::get().reads(36_u64))
+			.saturating_add(T::DbWeight::get().writes(35_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:x+16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+16 w:x+16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn nominate_multiple(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1985 + x * (252 ±0)`
+		//  Estimated: `46472 + x * (5042 ±0)`
+		// Minimum execution time: 143_531 nanoseconds.
+		Weight::from_ref_time(156_012_110)
+			.saturating_add(Weight::from_proof_size(46472))
+			// Standard Error: 30_940
+			.saturating_add(Weight::from_ref_time(10_208_699).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(33_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5042).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn chill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1300`
+		//  Estimated: `84456`
+		// Minimum execution time: 129_631 nanoseconds.
+		Weight::from_ref_time(140_904_615)
+			.saturating_add(Weight::from_proof_size(84456))
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(35_u64))
+	}
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn cancel_deferred_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `41 + x * (1 ±0)`
+		//  Estimated: `1530 + x * (25 ±0)`
+		// Minimum execution time: 11_979 nanoseconds.
+		Weight::from_ref_time(13_021_814)
+			.saturating_add(Weight::from_proof_size(1530))
+			// Standard Error: 25_810
+			.saturating_add(Weight::from_ref_time(408_288).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_proof_size(25).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking RewardPoints (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking RewardPoints (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:x w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingRewards (r:0 w:x)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking SessionSummaries (r:0 w:1)
+	/// Proof: DarwiniaStaking SessionSummaries (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	fn payout(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `355 + x * (289 ±0)`
+		//  Estimated: `2073 + x * (5791 ±0)`
+		// Minimum execution time: 28_155 nanoseconds.
+		Weight::from_ref_time(30_604_996)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 28_736
+			.saturating_add(Weight::from_ref_time(13_917_753).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5791).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking PendingRewards (r:1 w:1)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:x+1 w:0)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:x+1 w:x+1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x+1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 32]`.
+	fn payout_stakers(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2356 + x * (335 ±0)`
+		//  Estimated: `3819 + x * (6713 ±0)`
+		// Minimum execution time: 65_590 nanoseconds.
+		Weight::from_ref_time(71_294_454)
+			.saturating_add(Weight::from_proof_size(3819))
+			// Standard Error: 4_244
+			.saturating_add(Weight::from_ref_time(48_733_147).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(6713).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:0 w:1)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn set_payee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1624`
+		//  Estimated: `2576`
+		// Minimum execution time: 17_850 nanoseconds.
+		Weight::from_ref_time(19_403_093)
+			.saturating_add(Weight::from_proof_size(2576))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DarwiniaStaking Invulnerables (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking CollatorCount (r:0 w:1)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_collator_count() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `0`
+		// Minimum execution time: 11_483 nanoseconds.
+		Weight::from_ref_time(12_482_423)
+			.saturating_add(Weight::from_proof_size(0))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DarwiniaStaking CollatorCount (r:1 w:0)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Invulnerables (r:0 w:1)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 100]`.
+	fn set_invulnerables(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `499`
+		// Minimum execution time: 12_822 nanoseconds.
+		Weight::from_ref_time(13_937_076)
+			.saturating_add(Weight::from_proof_size(499))
+			// Standard Error: 19_912
+			.saturating_add(Weight::from_ref_time(621_556).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn force_chill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1300`
+		//  Estimated: `84456`
+		// Minimum execution time: 130_972 nanoseconds.
+		Weight::from_ref_time(142_361_952)
+			.saturating_add(Weight::from_proof_size(84456))
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(35_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:1 w:0)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:0 w:1)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn force_unstake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4916`
+		//  Estimated: `107443`
+		// Minimum execution time: 195_415 nanoseconds.
+		Weight::from_ref_time(212_408_369)
+			.saturating_add(Weight::from_proof_size(107443))
+			// Standard Error: 29_334
+			.saturating_add(Weight::from_ref_time(7_302_663).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(43_u64))
+			.saturating_add(T::DbWeight::get().writes(46_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn lock_and_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2871`
+		//  Estimated: `102278`
+		// Minimum execution time: 172_639 nanoseconds.
+		Weight::from_ref_time(187_652_846)
+			.saturating_add(Weight::from_proof_size(102278))
+			.saturating_add(T::DbWeight::get().reads(41_u64))
+			.saturating_add(T::DbWeight::get().writes(39_u64))
+	}
+	/// Storage: DarwiniaStaking PendingRewards (r:x w:x)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn expire_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (165 ±0)`
+		//  Estimated: `2073 + x * (3308 ±0)`
+		// Minimum execution time: 9_425 nanoseconds.
+		Weight::from_ref_time(10_245_601)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 24_996
+			.saturating_add(Weight::from_ref_time(4_913_576).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(3308).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking SessionSummaries (r:0 w:1)
+	/// Proof: DarwiniaStaking SessionSummaries (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalRewardPoints (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalRewardPoints (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn prune_history(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `2073`
+		// Minimum execution time: 10_039 nanoseconds.
+		Weight::from_ref_time(10_912_654)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 38_557
+			.saturating_add(Weight::from_ref_time(2_384_722).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+	}
+	/// Storage: DarwiniaStaking Invulnerables (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking AuthoredBlocks (r:1 w:0)
+	/// Proof: DarwiniaStaking AuthoredBlocks (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:x w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:x w:x)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingCommissions (r:0 w:x)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:x)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn chill_inactive_collators(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (167 ±0)`
+		//  Estimated: `4580 + x * (3348 ±0)`
+		// Minimum execution time: 9_694 nanoseconds.
+		Weight::from_ref_time(10_537_993)
+			.saturating_add(Weight::from_proof_size(4580))
+			// Standard Error: 35_515
+			.saturating_add(Weight::from_ref_time(14_806_034).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(3348).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking RewardPoints (r:1 w:1)
+	/// Proof Skipped: DarwiniaStaking RewardPoints (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking HistoricalRewardPoints (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalRewardPoints (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn clean_old_session(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + x * (165 ±0)`
+		//  Estimated: `2073 + x * (2519 ±0)`
+		// Minimum execution time: 11_413 nanoseconds.
+		Weight::from_ref_time(12_406_634)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 34_108
+			.saturating_add(Weight::from_ref_time(9_127_218).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(2519).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking PendingCommissions (r:x w:x)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn apply_commission_changes(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (125 ±0)`
+		//  Estimated: `2073 + x * (2516 ±0)`
+		// Minimum execution time: 8_470 nanoseconds.
+		Weight::from_ref_time(9_207_426)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 1_575
+			.saturating_add(Weight::from_ref_time(8_471_064).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(2516).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:x w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn drop_underbonded_collators(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (291 ±0)`
+		//  Estimated: `2073 + x * (5820 ±0)`
+		// Minimum execution time: 8_199 nanoseconds.
+		Weight::from_ref_time(8_913_007)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 34_431
+			.saturating_add(Weight::from_ref_time(9_614_671).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5820).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x w:0)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:0)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:0)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking CollatorCount (r:1 w:0)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:y w:0)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Exposures (r:x w:x)
+	/// Proof: DarwiniaStaking Exposures (max_values: None, max_size: Some(805), added: 3280, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	/// The range of component `y` is `[0, 1000]`.
+	fn elect(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1318 + x * (252 ±0) + y * (128 ±0)`
+		//  Estimated: `3594 + x * (8322 ±0) + y * (2563 ±0)`
+		// Minimum execution time: 24_476 nanoseconds.
+		Weight::from_ref_time(26_605_932)
+			.saturating_add(Weight::from_proof_size(3594))
+			// Standard Error: 5_496
+			.saturating_add(Weight::from_ref_time(12_884_369).saturating_mul(x.into()))
+			// Standard Error: 39_015
+			.saturating_add(Weight::from_ref_time(6_142_724).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(8322).saturating_mul(x.into()))
+			.saturating_add(Weight::from_proof_size(2563).saturating_mul(y.into()))
+	}
+	/// Storage: DarwiniaStaking HistoricalExposures (r:1 w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:x w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:0)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:0)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:0 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Events (r:0 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 32]`.
+	fn on_offence(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186 + x * (684 ±0)`
+		//  Estimated: `2560 + x * (15298 ±0)`
+		// Minimum execution time: 14_210 nanoseconds.
+		Weight::from_ref_time(14_210_000)
+			.saturating_add(Weight::from_proof_size(2560))
+			// Standard Error: 3_068_857
+			.saturating_add(Weight::from_ref_time(21_482_000).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_proof_size(15298).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:2 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:x)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System Account (r:x w:x)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:x w:x)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:x w:x)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:x w:x)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:x w:x)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x w:x)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Events (r:0 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 32]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1507 + x * (3021 ±0)`
+		//  Estimated: `101375 + x * (90431 ±0)`
+		// Minimum execution time: 1_102_871 nanoseconds.
+		Weight::from_ref_time(1_102_871_000)
+			.saturating_add(Weight::from_proof_size(101375))
+			// Standard Error: 156_330_285
+			.saturating_add(Weight::from_ref_time(1_094_312_000).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(46_u64))
+			.saturating_add(T::DbWeight::get().reads((46_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(39_u64))
+			.saturating_add(T::DbWeight::get().writes((39_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(90431).saturating_mul(x.into()))
+	}
+}
 
-pub trait WeightInfo {}
-impl WeightInfo for () {}
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn stake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2315`
+		//  Estimated: `104855`
+		// Minimum execution time: 185_263 nanoseconds.
+		Weight::from_ref_time(201_373_698)
+			.saturating_add(Weight::from_proof_size(104855))
+			// Standard Error: 7_003
+			.saturating_add(Weight::from_ref_time(6_118_622).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(41_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:1 w:1)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn unstake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3012`
+		//  Estimated: `94482`
+		// Minimum execution time: 140_876 nanoseconds.
+		Weight::from_ref_time(153_127_957)
+			.saturating_add(Weight::from_proof_size(94482))
+			// Standard Error: 34_752
+			.saturating_add(Weight::from_ref_time(2_963_632).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(36_u64))
+	}
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn restake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3324`
+		//  Estimated: `135890`
+		// Minimum execution time: 218_514 nanoseconds.
+		Weight::from_ref_time(237_516_036)
+			.saturating_add(Weight::from_proof_size(135890))
+			// Standard Error: 25_197
+			.saturating_add(Weight::from_ref_time(3_151_646).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(54_u64))
+			.saturating_add(RocksDbWeight::get().writes(51_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4127`
+		//  Estimated: `63373`
+		// Minimum execution time: 246_882 nanoseconds.
+		Weight::from_ref_time(268_351_448)
+			.saturating_add(Weight::from_proof_size(63373))
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(23_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Session NextKeys (r:1 w:0)
+	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Collators (r:1 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking CurrentSession (r:1 w:0)
+	/// Proof: DarwiniaStaking CurrentSession (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingCommissions (r:0 w:1)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking ChilledCommissions (r:1 w:1)
+	/// Proof: DarwiniaStaking ChilledCommissions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn collect() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1919`
+		//  Estimated: `11929`
+		// Minimum execution time: 35_232 nanoseconds.
+		Weight::from_ref_time(38_296_540)
+			.saturating_add(Weight::from_proof_size(11929))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:1 w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:17)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:17 w:17)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn nominate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1972`
+		//  Estimated: `92074`
+		// Minimum execution time: 147_767 nanoseconds.
+		Weight::from_ref_time(160_617_808)
+			.saturating_add(Weight::from_proof_size(92074))
+			.saturating_add(RocksDbWeight::get().reads(36_u64))
+			.saturating_add(RocksDbWeight::get().writes(35_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:x+16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+16 w:x+16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn nominate_multiple(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1985 + x * (252 ±0)`
+		//  Estimated: `46472 + x * (5042 ±0)`
+		// Minimum execution time: 143_531 nanoseconds.
+		Weight::from_ref_time(156_012_110)
+			.saturating_add(Weight::from_proof_size(46472))
+			// Standard Error: 30_940
+			.saturating_add(Weight::from_ref_time(10_208_699).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(33_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5042).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn chill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1300`
+		//  Estimated: `84456`
+		// Minimum execution time: 129_631 nanoseconds.
+		Weight::from_ref_time(140_904_615)
+			.saturating_add(Weight::from_proof_size(84456))
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(35_u64))
+	}
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn cancel_deferred_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `41 + x * (1 ±0)`
+		//  Estimated: `1530 + x * (25 ±0)`
+		// Minimum execution time: 11_979 nanoseconds.
+		Weight::from_ref_time(13_021_814)
+			.saturating_add(Weight::from_proof_size(1530))
+			// Standard Error: 25_810
+			.saturating_add(Weight::from_ref_time(408_288).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_proof_size(25).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking RewardPoints (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking RewardPoints (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:x w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingRewards (r:0 w:x)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking SessionSummaries (r:0 w:1)
+	/// Proof: DarwiniaStaking SessionSummaries (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	fn payout(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `355 + x * (289 ±0)`
+		//  Estimated: `2073 + x * (5791 ±0)`
+		// Minimum execution time: 28_155 nanoseconds.
+		Weight::from_ref_time(30_604_996)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 28_736
+			.saturating_add(Weight::from_ref_time(13_917_753).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5791).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking PendingRewards (r:1 w:1)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:x+1 w:0)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:x+1 w:x+1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x+1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 32]`.
+	fn payout_stakers(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2356 + x * (335 ±0)`
+		//  Estimated: `3819 + x * (6713 ±0)`
+		// Minimum execution time: 65_590 nanoseconds.
+		Weight::from_ref_time(71_294_454)
+			.saturating_add(Weight::from_proof_size(3819))
+			// Standard Error: 4_244
+			.saturating_add(Weight::from_ref_time(48_733_147).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(6713).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:0 w:1)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn set_payee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1624`
+		//  Estimated: `2576`
+		// Minimum execution time: 17_850 nanoseconds.
+		Weight::from_ref_time(19_403_093)
+			.saturating_add(Weight::from_proof_size(2576))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DarwiniaStaking Invulnerables (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking CollatorCount (r:0 w:1)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_collator_count() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `0`
+		// Minimum execution time: 11_483 nanoseconds.
+		Weight::from_ref_time(12_482_423)
+			.saturating_add(Weight::from_proof_size(0))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DarwiniaStaking CollatorCount (r:1 w:0)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Invulnerables (r:0 w:1)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 100]`.
+	fn set_invulnerables(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `499`
+		// Minimum execution time: 12_822 nanoseconds.
+		Weight::from_ref_time(13_937_076)
+			.saturating_add(Weight::from_proof_size(499))
+			// Standard Error: 19_912
+			.saturating_add(Weight::from_ref_time(621_556).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn force_chill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1300`
+		//  Estimated: `84456`
+		// Minimum execution time: 130_972 nanoseconds.
+		Weight::from_ref_time(142_361_952)
+			.saturating_add(Weight::from_proof_size(84456))
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(35_u64))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:1 w:0)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:0 w:1)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn force_unstake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4916`
+		//  Estimated: `107443`
+		// Minimum execution time: 195_415 nanoseconds.
+		Weight::from_ref_time(212_408_369)
+			.saturating_add(Weight::from_proof_size(107443))
+			// Standard Error: 29_334
+			.saturating_add(Weight::from_ref_time(7_302_663).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(43_u64))
+			.saturating_add(RocksDbWeight::get().writes(46_u64))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn lock_and_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2871`
+		//  Estimated: `102278`
+		// Minimum execution time: 172_639 nanoseconds.
+		Weight::from_ref_time(187_652_846)
+			.saturating_add(Weight::from_proof_size(102278))
+			.saturating_add(RocksDbWeight::get().reads(41_u64))
+			.saturating_add(RocksDbWeight::get().writes(39_u64))
+	}
+	/// Storage: DarwiniaStaking PendingRewards (r:x w:x)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn expire_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (165 ±0)`
+		//  Estimated: `2073 + x * (3308 ±0)`
+		// Minimum execution time: 9_425 nanoseconds.
+		Weight::from_ref_time(10_245_601)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 24_996
+			.saturating_add(Weight::from_ref_time(4_913_576).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(3308).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking SessionSummaries (r:0 w:1)
+	/// Proof: DarwiniaStaking SessionSummaries (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalRewardPoints (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalRewardPoints (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn prune_history(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `2073`
+		// Minimum execution time: 10_039 nanoseconds.
+		Weight::from_ref_time(10_912_654)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 38_557
+			.saturating_add(Weight::from_ref_time(2_384_722).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+	}
+	/// Storage: DarwiniaStaking Invulnerables (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking AuthoredBlocks (r:1 w:0)
+	/// Proof: DarwiniaStaking AuthoredBlocks (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:x w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:x w:x)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingCommissions (r:0 w:x)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:x)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn chill_inactive_collators(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (167 ±0)`
+		//  Estimated: `4580 + x * (3348 ±0)`
+		// Minimum execution time: 9_694 nanoseconds.
+		Weight::from_ref_time(10_537_993)
+			.saturating_add(Weight::from_proof_size(4580))
+			// Standard Error: 35_515
+			.saturating_add(Weight::from_ref_time(14_806_034).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(3348).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking RewardPoints (r:1 w:1)
+	/// Proof Skipped: DarwiniaStaking RewardPoints (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking HistoricalRewardPoints (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalRewardPoints (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn clean_old_session(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + x * (165 ±0)`
+		//  Estimated: `2073 + x * (2519 ±0)`
+		// Minimum execution time: 11_413 nanoseconds.
+		Weight::from_ref_time(12_406_634)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 34_108
+			.saturating_add(Weight::from_ref_time(9_127_218).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(2519).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking PendingCommissions (r:x w:x)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn apply_commission_changes(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (125 ±0)`
+		//  Estimated: `2073 + x * (2516 ±0)`
+		// Minimum execution time: 8_470 nanoseconds.
+		Weight::from_ref_time(9_207_426)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 1_575
+			.saturating_add(Weight::from_ref_time(8_471_064).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(2516).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:x w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn drop_underbonded_collators(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (291 ±0)`
+		//  Estimated: `2073 + x * (5820 ±0)`
+		// Minimum execution time: 8_199 nanoseconds.
+		Weight::from_ref_time(8_913_007)
+			.saturating_add(Weight::from_proof_size(2073))
+			// Standard Error: 34_431
+			.saturating_add(Weight::from_ref_time(9_614_671).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(5820).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x w:0)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:0)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:0)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking CollatorCount (r:1 w:0)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:y w:0)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Exposures (r:x w:x)
+	/// Proof: DarwiniaStaking Exposures (max_values: None, max_size: Some(805), added: 3280, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	/// The range of component `y` is `[0, 1000]`.
+	fn elect(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1318 + x * (252 ±0) + y * (128 ±0)`
+		//  Estimated: `3594 + x * (8322 ±0) + y * (2563 ±0)`
+		// Minimum execution time: 24_476 nanoseconds.
+		Weight::from_ref_time(26_605_932)
+			.saturating_add(Weight::from_proof_size(3594))
+			// Standard Error: 5_496
+			.saturating_add(Weight::from_ref_time(12_884_369).saturating_mul(x.into()))
+			// Standard Error: 39_015
+			.saturating_add(Weight::from_ref_time(6_142_724).saturating_mul(y.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(y.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(8322).saturating_mul(x.into()))
+			.saturating_add(Weight::from_proof_size(2563).saturating_mul(y.into()))
+	}
+	/// Storage: DarwiniaStaking HistoricalExposures (r:1 w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:x w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:0)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:0)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:0 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Events (r:0 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 32]`.
+	fn on_offence(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186 + x * (684 ±0)`
+		//  Estimated: `2560 + x * (15298 ±0)`
+		// Minimum execution time: 14_210 nanoseconds.
+		Weight::from_ref_time(14_210_000)
+			.saturating_add(Weight::from_proof_size(2560))
+			// Standard Error: 3_068_857
+			.saturating_add(Weight::from_ref_time(21_482_000).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_proof_size(15298).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:2 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:x)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System Account (r:x w:x)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:x w:x)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:x w:x)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:x w:x)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:x w:x)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x w:x)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Events (r:0 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 32]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1507 + x * (3021 ±0)`
+		//  Estimated: `101375 + x * (90431 ±0)`
+		// Minimum execution time: 1_102_871 nanoseconds.
+		Weight::from_ref_time(1_102_871_000)
+			.saturating_add(Weight::from_proof_size(101375))
+			// Standard Error: 156_330_285
+			.saturating_add(Weight::from_ref_time(1_094_312_000).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(46_u64))
+			.saturating_add(RocksDbWeight::get().reads((46_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(39_u64))
+			.saturating_add(RocksDbWeight::get().writes((39_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_proof_size(90431).saturating_mul(x.into()))
+	}
+}
@@ -20,7 +20,7 @@
 use darwinia_staking::*;
 use dc_types::{AssetId, Balance, Moment, UNIT};
 // substrate
-use frame_support::traits::{GenesisBuild, OnInitialize};
+use frame_support::traits::{EstimateNextSessionRotation, GenesisBuild, OnInitialize};
 use sp_io::TestExternalities;
 use sp_runtime::RuntimeAppPublic;
 
@@ -123,11 +123,17 @@ impl darwinia_deposit::SimpleAsset for KtonAsset {
 	}
 }
 impl darwinia_deposit::Config for Runtime {
+	type DepositInterest = ();
 	type Kton = KtonAsset;
+	type LinearVesting = frame_support::traits::ConstBool<false>;
 	type MaxDeposits = frame_support::traits::ConstU32<16>;
+	type MaxLockingMonths = frame_support::traits::ConstU8<36>;
 	type MinLockingAmount = frame_support::traits::ConstU128<UNIT>;
+	type MinLockingMonths = frame_support::traits::ConstU8<1>;
+	type PenaltyMultiplier = frame_support::traits::ConstU32<3>;
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type Staking = Staking;
 	type WeightInfo = ();
 }
 
@@ -154,6 +160,21 @@ impl darwinia_staking::Stake for RingStaking {
 		)
 	}
 }
+impl darwinia_staking::Slash for RingStaking {
+	fn slash(
+		_: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let amount = fraction * item;
+		let (_, unslashed) = <Balances as frame_support::traits::Currency<_>>::slash(
+			&darwinia_staking::account_id(),
+			amount,
+		);
+
+		Ok(amount - unslashed)
+	}
+}
 
 frame_support::parameter_types! {
 	pub static SessionHandlerCollators: Vec<u32> = Vec::new();
@@ -199,6 +220,31 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = ();
 }
 
+frame_support::parameter_types! {
+	pub static MergeUnstakingsBySession: bool = false;
+}
+// Same as `()` unless the static is changed.
+pub enum UnstakingSessionRotation {}
+impl EstimateNextSessionRotation<u64> for UnstakingSessionRotation {
+	fn average_session_length() -> u64 {
+		<Period as frame_support::traits::Get<u64>>::get()
+	}
+
+	fn estimate_current_session_progress(
+		_: u64,
+	) -> (Option<sp_runtime::Permill>, frame_support::weights::Weight) {
+		(None, Default::default())
+	}
+
+	fn estimate_next_session_rotation(now: u64) -> (Option<u64>, frame_support::weights::Weight) {
+		if MergeUnstakingsBySession::get() {
+			<pallet_session::PeriodicSessions<Period, ()>>::estimate_next_session_rotation(now)
+		} else {
+			(None, Default::default())
+		}
+	}
+}
+
 pub enum KtonStaking {}
 impl darwinia_staking::Stake for KtonStaking {
 	type AccountId = u32;
@@ -222,21 +268,81 @@ impl darwinia_staking::Stake for KtonStaking {
 		)
 	}
 }
+impl darwinia_staking::Slash for KtonStaking {
+	fn slash(
+		_: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let amount = fraction * item;
+
+		Assets::burn(RuntimeOrigin::signed(0), 0.into(), darwinia_staking::account_id(), amount)?;
+
+		Ok(amount)
+	}
+}
+frame_support::parameter_types! {
+	pub static KtonPowerShare: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	pub static DepositPowerBonus: sp_runtime::Perbill = sp_runtime::Perbill::zero();
+}
+// Same as the default calculation unless the statics are changed.
+pub enum CustomPowerCalculator {}
+impl darwinia_staking::PowerCalculator<u32, darwinia_deposit::DepositId> for CustomPowerCalculator {
+	fn deposit2ring(_: &u32, _: darwinia_deposit::DepositId, amount: Balance) -> Balance {
+		amount + DepositPowerBonus::get() * amount
+	}
+
+	fn backing2power(backing: &Backing, ring_pool: Balance, kton_pool: Balance) -> Power {
+		let balance2power = |amount, pool: Balance, share: sp_runtime::Perbill| {
+			(sp_runtime::Perquintill::from_rational(amount, pool.max(1))
+				* (share * 1_000_000_000_u128)) as Power
+		};
+		let kton_share = KtonPowerShare::get();
+
+		balance2power(backing.ring, ring_pool, kton_share.left_from_one())
+			+ balance2power(backing.kton, kton_pool, kton_share)
+	}
+}
 frame_support::parameter_types! {
 	pub const PayoutFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(40);
+	pub const MaxSlashFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	pub static MinCollatorStake: Balance = 0;
+	pub static MaxCommission: sp_runtime::Perbill = sp_runtime::Perbill::one();
+	pub static MaxCommissionDelta: sp_runtime::Perbill = sp_runtime::Perbill::one();
+	pub static MinAuthoredBlocks: u32 = 0;
+	pub static InactivitySlashFraction: sp_runtime::Perbill = sp_runtime::Perbill::zero();
 }
 impl darwinia_staking::Config for Runtime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CommissionChangeDelay = frame_support::traits::ConstU32<2>;
 	type Deposit = Deposit;
+	type HistoryDepth = frame_support::traits::ConstU32<3>;
+	type InactivitySlashFraction = InactivitySlashFraction;
+	type KeysRegistry = darwinia_staking::SessionKeysRegistry<Self>;
 	type Kton = KtonStaking;
+	type ManagerOrigin = frame_system::EnsureRoot<u32>;
+	type MaxCommission = MaxCommission;
+	type MaxCommissionDelta = MaxCommissionDelta;
 	type MaxDeposits = frame_support::traits::ConstU32<16>;
-	type MaxUnstakings = frame_support::traits::ConstU32<16>;
+	type MaxInactiveSessions = frame_support::traits::ConstU32<2>;
+	type MaxNominations = frame_support::traits::ConstU32<16>;
+	type MaxNominatorsPerCollator = frame_support::traits::ConstU32<3>;
+	type MaxSlashFraction = MaxSlashFraction;
+	type MinAuthoredBlocks = MinAuthoredBlocks;
+	type MinCollatorStake = MinCollatorStake;
 	type MinStakingDuration = frame_support::traits::ConstU64<3>;
+	type NextSessionRotation = UnstakingSessionRotation;
 	type PayoutFraction = PayoutFraction;
+	type PowerCalculator = CustomPowerCalculator;
 	type RewardRemainder = ();
 	type Ring = RingStaking;
 	type RingCurrency = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type SlashCancelOrigin = frame_system::EnsureRoot<u32>;
+	type SlashDeferDuration = frame_support::traits::ConstU32<1>;
 	type UnixTime = Time;
+	type WeightInfo = ();
 }
 
 frame_support::construct_runtime! {
@@ -264,13 +370,21 @@ impl ZeroDefault for Ledger<Runtime> {
 			staked_ring: Default::default(),
 			staked_kton: Default::default(),
 			staked_deposits: Default::default(),
-			unstaking_ring: Default::default(),
-			unstaking_kton: Default::default(),
-			unstaking_deposits: Default::default(),
 		}
 	}
 }
 
+// Return the (claimable block, RING, KTON, deposits) of the unstakings, sorted by the block.
+pub fn unstakings_of(who: u32) -> Vec<(u64, Balance, Balance, Vec<darwinia_deposit::DepositId>)> {
+	let mut us = <Unstakings<Runtime>>::iter_prefix(who)
+		.map(|(t, u)| (t, u.ring, u.kton, u.deposits.into_inner()))
+		.collect::<Vec<_>>();
+
+	us.sort_by_key(|(t, ..)| *t);
+
+	us
+}
+
 pub enum Efflux {}
 impl Efflux {
 	pub fn time(milli_secs: Moment) {
@@ -327,7 +441,16 @@ impl ExtBuilder {
 
 		let mut ext = TestExternalities::from(storage);
 
-		ext.execute_with(|| initialize_block(1));
+		ext.execute_with(|| {
+			(1..=10).for_each(|i| {
+				<pallet_session::NextKeys<Runtime>>::insert(
+					i,
+					SessionKeys { aura: sp_runtime::testing::UintAuthorityId(i as _) },
+				)
+			});
+
+			initialize_block(1);
+		});
 
 		ext
 	}
@@ -27,7 +27,9 @@ use darwinia_staking::*;
 use dc_types::{Balance, UNIT};
 // substrate
 use frame_support::{assert_noop, assert_ok, BoundedVec};
-use sp_runtime::{assert_eq_error_rate, Perbill};
+use pallet_session::SessionManager;
+use sp_runtime::{assert_eq_error_rate, DispatchError, Perbill};
+use sp_staking::offence::{DisableStrategy, OffenceDetails, OnOffenceHandler};
 
 #[test]
 fn stake_should_work() {
@@ -94,6 +96,31 @@ fn stake_should_work() {
 	});
 }
 
+#[test]
+fn lock_and_stake_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_ok!(Staking::lock_and_stake(RuntimeOrigin::signed(1), 2 * UNIT, 1));
+		assert_eq!(Balances::free_balance(1), 997 * UNIT);
+		assert_eq!(Deposit::deposit_of(1).unwrap()[1].value, 2 * UNIT);
+		assert!(Deposit::deposit_of(1).unwrap()[1].in_use);
+		assert_eq!(
+			Staking::ledger_of(1).unwrap(),
+			Ledger {
+				staked_deposits: BoundedVec::truncate_from(vec![1]),
+				..ZeroDefault::default()
+			}
+		);
+		assert_eq!(Staking::ring_pool(), 2 * UNIT);
+
+		// Nothing changes if the lock fails.
+		assert_noop!(
+			Staking::lock_and_stake(RuntimeOrigin::signed(1), UNIT, 0),
+			<DepositError<Runtime>>::LockTooShort
+		);
+	});
+}
+
 #[test]
 fn unstake_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -121,10 +148,17 @@ fn unstake_should_work() {
 				staked_ring: 2 * UNIT,
 				staked_kton: 3 * UNIT,
 				staked_deposits: BoundedVec::truncate_from(vec![0, 1, 2]),
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4)]),
 				..ZeroDefault::default()
 			}
 		);
+		assert_eq!(unstakings_of(1), vec![(4, UNIT, 0, Vec::new())]);
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::Unstaked {
+				staker: 1,
+				ring_amount: UNIT,
+				kton_amount: 0,
+				deposits: Vec::new()
+			})));
 
 		// Unstake 1 KTON.
 		Efflux::block(1);
@@ -135,11 +169,10 @@ fn unstake_should_work() {
 				staked_ring: 2 * UNIT,
 				staked_kton: 2 * UNIT,
 				staked_deposits: BoundedVec::truncate_from(vec![0, 1, 2]),
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 5)]),
 				..ZeroDefault::default()
 			}
 		);
+		assert_eq!(unstakings_of(1), vec![(4, UNIT, 0, Vec::new()), (5, 0, UNIT, Vec::new())]);
 
 		// Unstake invalid deposit.
 		assert_noop!(
@@ -156,23 +189,25 @@ fn unstake_should_work() {
 				staked_ring: 2 * UNIT,
 				staked_kton: 2 * UNIT,
 				staked_deposits: BoundedVec::truncate_from(vec![0, 2]),
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 5)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(1, 6)])
 			}
 		);
+		assert_eq!(
+			unstakings_of(1),
+			vec![(4, UNIT, 0, Vec::new()), (5, 0, UNIT, Vec::new()), (6, 0, 0, vec![1])]
+		);
 
 		// Unstake 2 RING, 2 KTON and 2 deposits.
 		Efflux::block(1);
 		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), 2 * UNIT, 2 * UNIT, vec![0, 2]));
+		assert_eq!(Staking::ledger_of(1).unwrap(), ZeroDefault::default());
 		assert_eq!(
-			Staking::ledger_of(1).unwrap(),
-			Ledger {
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4), (2 * UNIT, 7)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 5), (2 * UNIT, 7)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(1, 6), (0, 7), (2, 7)]),
-				..ZeroDefault::default()
-			}
+			unstakings_of(1),
+			vec![
+				(4, UNIT, 0, Vec::new()),
+				(5, 0, UNIT, Vec::new()),
+				(6, 0, 0, vec![1]),
+				(7, 2 * UNIT, 2 * UNIT, vec![0, 2])
+			]
 		);
 
 		// Keep the stakes for at least `MinStakingDuration`.
@@ -195,28 +230,37 @@ fn restake_should_work() {
 		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, UNIT, Vec::new()));
 		assert_eq!(Balances::free_balance(1), 994 * UNIT);
 		assert_eq!(Assets::balance(0, 1), 997 * UNIT + 22_842_639_593_907);
+		assert_eq!(Staking::ledger_of(1).unwrap(), ZeroDefault::default());
 		assert_eq!(
-			Staking::ledger_of(1).unwrap(),
-			Ledger {
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT, 5), (UNIT, 6)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT, 5), (UNIT, 6)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(0, 4), (1, 4), (2, 4)]),
-				..ZeroDefault::default()
-			}
+			unstakings_of(1),
+			vec![
+				(4, UNIT, UNIT, vec![0, 1, 2]),
+				(5, UNIT, UNIT, Vec::new()),
+				(6, UNIT, UNIT, Vec::new())
+			]
 		);
 
 		// Restake 1.5 RING.
 		assert_ok!(Staking::restake(RuntimeOrigin::signed(1), 3 * UNIT / 2, 0, Vec::new()));
 		assert_eq!(
 			Staking::ledger_of(1).unwrap(),
-			Ledger {
-				staked_ring: 3 * UNIT / 2,
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT / 2, 5)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT, 5), (UNIT, 6)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(0, 4), (1, 4), (2, 4)]),
-				..ZeroDefault::default()
-			}
+			Ledger { staked_ring: 3 * UNIT / 2, ..ZeroDefault::default() }
+		);
+		assert_eq!(
+			unstakings_of(1),
+			vec![
+				(4, UNIT, UNIT, vec![0, 1, 2]),
+				(5, UNIT / 2, UNIT, Vec::new()),
+				(6, 0, UNIT, Vec::new())
+			]
 		);
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::Restaked {
+				staker: 1,
+				ring_amount: 3 * UNIT / 2,
+				kton_amount: 0,
+				deposits: Vec::new()
+			})));
 
 		// Restake 1.5 KTON.
 		assert_ok!(Staking::restake(RuntimeOrigin::signed(1), 0, 3 * UNIT / 2, Vec::new()));
@@ -225,12 +269,13 @@ fn restake_should_work() {
 			Ledger {
 				staked_ring: 3 * UNIT / 2,
 				staked_kton: 3 * UNIT / 2,
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT / 2, 5)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT / 2, 5)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(0, 4), (1, 4), (2, 4)]),
 				..ZeroDefault::default()
 			}
 		);
+		assert_eq!(
+			unstakings_of(1),
+			vec![(4, UNIT, UNIT, vec![0, 1, 2]), (5, UNIT / 2, UNIT / 2, Vec::new())]
+		);
 
 		// Restake invalid deposit.
 		assert_noop!(
@@ -246,11 +291,12 @@ fn restake_should_work() {
 				staked_ring: 3 * UNIT / 2,
 				staked_kton: 3 * UNIT / 2,
 				staked_deposits: BoundedVec::truncate_from(vec![1]),
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT / 2, 5)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT / 2, 5)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(0, 4), (2, 4)]),
 			}
 		);
+		assert_eq!(
+			unstakings_of(1),
+			vec![(4, UNIT, UNIT, vec![0, 2]), (5, UNIT / 2, UNIT / 2, Vec::new())]
+		);
 
 		// Restake 1.5 RING, 1.5 KTON and 2 deposits.
 		Efflux::block(1);
@@ -269,6 +315,7 @@ fn restake_should_work() {
 				..ZeroDefault::default()
 			}
 		);
+		assert!(unstakings_of(1).is_empty());
 	});
 }
 
@@ -289,14 +336,15 @@ fn claim_should_work() {
 		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, UNIT, vec![1, 2]));
 		assert_eq!(Balances::free_balance(1), 995 * UNIT);
 		assert_eq!(Assets::balance(0, 1), 998 * UNIT + 22_842_639_593_907);
+		assert_eq!(Staking::ledger_of(1).unwrap(), ZeroDefault::default());
 		assert_eq!(
-			Staking::ledger_of(1).unwrap(),
-			Ledger {
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 4), (UNIT, 7)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 5), (UNIT, 7)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(0, 6), (1, 7), (2, 7)]),
-				..ZeroDefault::default()
-			}
+			unstakings_of(1),
+			vec![
+				(4, UNIT, 0, Vec::new()),
+				(5, 0, UNIT, Vec::new()),
+				(6, 0, 0, vec![0]),
+				(7, UNIT, UNIT, vec![1, 2])
+			]
 		);
 
 		// 4 expired.
@@ -304,13 +352,8 @@ fn claim_should_work() {
 		assert_eq!(System::account(1).consumers, 2);
 		assert_eq!(Balances::free_balance(1), 996 * UNIT);
 		assert_eq!(
-			Staking::ledger_of(1).unwrap(),
-			Ledger {
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 7)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 5), (UNIT, 7)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(0, 6), (1, 7), (2, 7)]),
-				..ZeroDefault::default()
-			}
+			unstakings_of(1),
+			vec![(5, 0, UNIT, Vec::new()), (6, 0, 0, vec![0]), (7, UNIT, UNIT, vec![1, 2])]
 		);
 
 		// 5 expired.
@@ -318,30 +361,14 @@ fn claim_should_work() {
 		assert_ok!(Staking::claim(RuntimeOrigin::signed(1)));
 		assert_eq!(System::account(1).consumers, 2);
 		assert_eq!(Assets::balance(0, 1), 999 * UNIT + 22_842_639_593_907);
-		assert_eq!(
-			Staking::ledger_of(1).unwrap(),
-			Ledger {
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 7)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 7)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(0, 6), (1, 7), (2, 7)]),
-				..ZeroDefault::default()
-			}
-		);
+		assert_eq!(unstakings_of(1), vec![(6, 0, 0, vec![0]), (7, UNIT, UNIT, vec![1, 2])]);
 
 		// 6 expired.
 		Efflux::block(1);
 		assert_ok!(Staking::claim(RuntimeOrigin::signed(1)));
 		assert_eq!(System::account(1).consumers, 2);
 		assert_eq!(Assets::balance(0, 1), 999 * UNIT + 22_842_639_593_907);
-		assert_eq!(
-			Staking::ledger_of(1).unwrap(),
-			Ledger {
-				unstaking_ring: BoundedVec::truncate_from(vec![(UNIT, 7)]),
-				unstaking_kton: BoundedVec::truncate_from(vec![(UNIT, 7)]),
-				unstaking_deposits: BoundedVec::truncate_from(vec![(1, 7), (2, 7)]),
-				..ZeroDefault::default()
-			}
-		);
+		assert_eq!(unstakings_of(1), vec![(7, UNIT, UNIT, vec![1, 2])]);
 
 		// 7 expired.
 		Efflux::block(2);
@@ -350,6 +377,49 @@ fn claim_should_work() {
 		assert_eq!(Balances::free_balance(1), 997 * UNIT);
 		assert_eq!(Assets::balance(0, 1), 1_000 * UNIT + 22_842_639_593_907);
 		assert!(Staking::ledger_of(1).is_none());
+		assert!(unstakings_of(1).is_empty());
+	});
+}
+
+#[test]
+fn unstakings_should_be_merged() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 100 * UNIT, 100 * UNIT, Vec::new()));
+
+		// The unstakings in the same block mature at the same block.
+		(0..20).for_each(|_| {
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, UNIT, Vec::new()));
+		});
+		assert_eq!(unstakings_of(1), vec![(4, 20 * UNIT, 20 * UNIT, Vec::new())]);
+
+		// No limit on the unstakings which mature at different blocks.
+		(0..20).for_each(|_| {
+			Efflux::block(1);
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		});
+		assert_eq!(unstakings_of(1).len(), 21);
+		assert_eq!(unstakings_of(1)[20], (24, UNIT, 0, Vec::new()));
+
+		// The unstakings which mature in the same session are merged into the end of the
+		// session.
+		MergeUnstakingsBySession::set(true);
+		(0..5).for_each(|_| {
+			Efflux::block(1);
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		});
+		assert_eq!(unstakings_of(1).len(), 23);
+		assert_eq!(unstakings_of(1)[21], (27, 2 * UNIT, 0, Vec::new()));
+		assert_eq!(unstakings_of(1)[22], (30, 3 * UNIT, 0, Vec::new()));
+		assert_eq!(
+			Staking::ledger_of(1).unwrap(),
+			Ledger { staked_ring: 55 * UNIT, staked_kton: 80 * UNIT, ..ZeroDefault::default() }
+		);
+
+		// Claim all the matured ones at once.
+		Efflux::block(4);
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(1)));
+		assert!(unstakings_of(1).is_empty());
+		assert_eq!(Balances::free_balance(1), 945 * UNIT);
 	});
 }
 
@@ -359,15 +429,143 @@ fn collect_should_work() {
 		assert!(Staking::collator_of(1).is_none());
 		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
 
-		(0..=100).for_each(|c| {
+		// The decreases take effect immediately.
+		(0..=100).rev().for_each(|c| {
 			let c = Perbill::from_percent(c);
 
 			assert_ok!(Staking::collect(RuntimeOrigin::signed(1), c));
 			assert_eq!(Staking::collator_of(1).unwrap(), c);
+			assert!(System::events().into_iter().any(|r| r.event
+				== RuntimeEvent::Staking(Event::CommissionSet { collator: 1, commission: c })));
 		});
 	});
 }
 
+#[test]
+fn commission_change_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		MaxCommissionDelta::set(Perbill::from_percent(5));
+
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)));
+		assert_eq!(Staking::collator_of(1).unwrap(), Perbill::from_percent(10));
+		assert_noop!(
+			Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(16)),
+			<Error<Runtime>>::ExceedMaxCommissionDelta
+		);
+
+		// `CommissionChangeDelay` is 2.
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(15)));
+		assert_eq!(Staking::collator_of(1).unwrap(), Perbill::from_percent(10));
+		assert_eq!(Staking::pending_commission_of(1), Some((Perbill::from_percent(15), 2)));
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::CommissionChangeScheduled {
+				collator: 1,
+				commission: Perbill::from_percent(15),
+				session: 2
+			})));
+
+		<Staking as SessionManager<_>>::start_session(1);
+		assert_eq!(Staking::collator_of(1).unwrap(), Perbill::from_percent(10));
+		<Staking as SessionManager<_>>::start_session(2);
+		assert_eq!(Staking::collator_of(1).unwrap(), Perbill::from_percent(15));
+		assert!(Staking::pending_commission_of(1).is_none());
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::CommissionChangeApplied {
+				collator: 1,
+				commission: Perbill::from_percent(15)
+			})));
+
+		// A decrease cancels the pending increase.
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(20)));
+		assert_eq!(Staking::pending_commission_of(1), Some((Perbill::from_percent(20), 4)));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(5)));
+		assert_eq!(Staking::collator_of(1).unwrap(), Perbill::from_percent(5));
+		assert!(Staking::pending_commission_of(1).is_none());
+
+		// Chill cancels the pending increase.
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)));
+		assert!(Staking::pending_commission_of(1).is_some());
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(1)));
+		assert!(Staking::pending_commission_of(1).is_none());
+	});
+}
+
+#[test]
+fn chill_should_not_bypass_commission_change_delay() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		MaxCommissionDelta::set(Perbill::from_percent(5));
+
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(2), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Staking::elect(0), vec![1]);
+		assert_eq!(Staking::exposure_of(1).unwrap().commission, Perbill::from_percent(10));
+
+		// The commission before chilling is kept.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(1)));
+		assert!(Staking::collator_of(1).is_none());
+		assert_eq!(Staking::chilled_commission_of(1), Some(Perbill::from_percent(10)));
+
+		// Re-collecting with a higher commission is still capped and delayed.
+		assert_noop!(
+			Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(16)),
+			<Error<Runtime>>::ExceedMaxCommissionDelta
+		);
+		// `CommissionChangeDelay` is 2.
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(15)));
+		assert_eq!(Staking::collator_of(1), Some(Perbill::from_percent(10)));
+		assert_eq!(Staking::pending_commission_of(1), Some((Perbill::from_percent(15), 2)));
+		assert!(Staking::chilled_commission_of(1).is_none());
+
+		// The payout uses the commission recorded at the election.
+		<Staking as SessionManager<_>>::start_session(2);
+		assert_eq!(Staking::collator_of(1), Some(Perbill::from_percent(15)));
+		Staking::reward_by_ids(&[(1, 20)]);
+		Staking::payout(0, Duration::new(6 * 60 * 60, 0).as_millis(), Staking::elapsed_time());
+		assert_eq!(Staking::pending_reward_of(0, 1).unwrap().commission, Perbill::from_percent(10));
+
+		// The kept commission is removed together with the ledger.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(1)));
+		assert!(Staking::chilled_commission_of(1).is_some());
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		Efflux::block(3);
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(1)));
+		assert!(Staking::ledger_of(1).is_none());
+		assert!(Staking::chilled_commission_of(1).is_none());
+	});
+}
+
+#[test]
+fn collect_requirements_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		MinCollatorStake::set(UNIT);
+		MaxCommission::set(Perbill::from_percent(30));
+
+		// KTON is not counted as the self-stake.
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT - 1, UNIT, Vec::new()));
+		assert_noop!(
+			Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)),
+			<Error<Runtime>>::InsufficientSelfStake
+		);
+
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 1, 0, Vec::new()));
+		assert_noop!(
+			Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(31)),
+			<Error<Runtime>>::CommissionTooHigh
+		);
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(30)));
+
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(2), UNIT, 0, Vec::new()));
+		<pallet_session::NextKeys<Runtime>>::remove(2);
+		assert_noop!(
+			Staking::collect(RuntimeOrigin::signed(2), Perbill::from_percent(10)),
+			<Error<Runtime>>::NoSessionKeys
+		);
+	});
+}
+
 #[test]
 fn nominate_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -378,11 +576,89 @@ fn nominate_should_work() {
 			assert!(Staking::nominator_of(n).is_none());
 			assert_ok!(Staking::stake(RuntimeOrigin::signed(n), UNIT, 0, Vec::new()));
 			assert_ok!(Staking::nominate(RuntimeOrigin::signed(n), 1));
-			assert_eq!(Staking::nominator_of(n).unwrap(), 1);
+			assert_eq!(Staking::nominator_of(n).unwrap().into_inner(), vec![(1, Perbill::one())]);
 		});
 	});
 }
 
+#[test]
+fn nominate_multiple_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		(1..=3).for_each(|c| {
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(c), Default::default()));
+		});
+
+		assert_noop!(
+			Staking::nominate_multiple(RuntimeOrigin::signed(4), vec![1, 2], Vec::new()),
+			<Error<Runtime>>::NotStaker
+		);
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(4), UNIT, 0, Vec::new()));
+		assert_noop!(
+			Staking::nominate_multiple(RuntimeOrigin::signed(4), Vec::new(), Vec::new()),
+			<Error<Runtime>>::EmptyTargets
+		);
+		assert_noop!(
+			Staking::nominate_multiple(RuntimeOrigin::signed(4), vec![1; 17], Vec::new()),
+			<Error<Runtime>>::ExceedMaxNominations
+		);
+		assert_noop!(
+			Staking::nominate_multiple(RuntimeOrigin::signed(4), vec![1, 1], Vec::new()),
+			<Error<Runtime>>::DuplicateTarget
+		);
+		assert_noop!(
+			Staking::nominate_multiple(RuntimeOrigin::signed(4), vec![1, 5], Vec::new()),
+			<Error<Runtime>>::TargetNotCollator
+		);
+		assert_noop!(
+			Staking::nominate_multiple(RuntimeOrigin::signed(4), vec![1, 2], vec![Perbill::one()]),
+			<Error<Runtime>>::InvalidNominationWeights
+		);
+		assert_noop!(
+			Staking::nominate_multiple(
+				RuntimeOrigin::signed(4),
+				vec![1, 2],
+				vec![Perbill::from_percent(50), Perbill::from_percent(40)]
+			),
+			<Error<Runtime>>::InvalidNominationWeights
+		);
+		assert_noop!(
+			Staking::nominate_multiple(
+				RuntimeOrigin::signed(4),
+				vec![1, 2],
+				vec![Perbill::one(), Perbill::zero()]
+			),
+			<Error<Runtime>>::InvalidNominationWeights
+		);
+
+		// Explicit weights.
+		assert_ok!(Staking::nominate_multiple(
+			RuntimeOrigin::signed(4),
+			vec![1, 2],
+			vec![Perbill::from_percent(25), Perbill::from_percent(75)]
+		));
+		assert_eq!(
+			Staking::nominator_of(4).unwrap().into_inner(),
+			vec![(1, Perbill::from_percent(25)), (2, Perbill::from_percent(75))]
+		);
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::Nominated {
+				nominator: 4,
+				nominations: vec![(1, Perbill::from_percent(25)), (2, Perbill::from_percent(75))]
+			})));
+
+		// Even split.
+		assert_ok!(Staking::nominate_multiple(RuntimeOrigin::signed(4), vec![1, 2, 3], Vec::new()));
+		assert_eq!(
+			Staking::nominator_of(4).unwrap().into_inner(),
+			vec![
+				(1, Perbill::from_parts(333_333_334)),
+				(2, Perbill::from_parts(333_333_333)),
+				(3, Perbill::from_parts(333_333_333))
+			]
+		);
+	});
+}
+
 #[test]
 fn chill_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -465,6 +741,31 @@ fn power_should_work() {
 	});
 }
 
+#[test]
+fn power_calculator_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(2), 0, UNIT, Vec::new()));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(3), UNIT, 1));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(3), 0, 0, vec![0]));
+		assert_eq!(Staking::power_of(&1), 250_000_000);
+		assert_eq!(Staking::power_of(&2), 500_000_000);
+		assert_eq!(Staking::power_of(&3), 250_000_000);
+
+		// KTON takes 80% of the power.
+		KtonPowerShare::set(Perbill::from_percent(80));
+		assert_eq!(Staking::power_of(&1), 100_000_000);
+		assert_eq!(Staking::power_of(&2), 800_000_000);
+		assert_eq!(Staking::power_of(&3), 100_000_000);
+
+		// Deposits count as 150% of their face value.
+		DepositPowerBonus::set(Perbill::from_percent(50));
+		assert_eq!(Staking::power_of(&1), 100_000_000);
+		assert_eq!(Staking::power_of(&2), 800_000_000);
+		assert_eq!(Staking::power_of(&3), 150_000_000);
+	});
+}
+
 #[test]
 fn elect_should_work() {
 	ExtBuilder::default().collator_count(3).build().execute_with(|| {
@@ -488,7 +789,7 @@ fn elect_should_work() {
 			assert_ok!(Staking::nominate(RuntimeOrigin::signed(i), i - 5));
 		});
 
-		assert_eq!(Staking::elect(), vec![5, 4, 3]);
+		assert_eq!(Staking::elect(0), vec![5, 4, 3]);
 	});
 	ExtBuilder::default().collator_count(3).build().execute_with(|| {
 		(1..=5).for_each(|i| {
@@ -510,7 +811,39 @@ fn elect_should_work() {
 			assert_ok!(Staking::nominate(RuntimeOrigin::signed(i), i - 5));
 		});
 
-		assert_eq!(Staking::elect(), vec![1, 2, 3]);
+		assert_eq!(Staking::elect(0), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn drop_underbonded_collators_should_work() {
+	ExtBuilder::default().collator_count(3).build().execute_with(|| {
+		MinCollatorStake::set(UNIT);
+
+		(1..=3).for_each(|i| {
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(i), UNIT, 0, Vec::new()));
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(i), Default::default()));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(i), i));
+		});
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(2), 1, 0, Vec::new()));
+
+		Staking::drop_underbonded_collators();
+		assert!(Staking::collator_of(1).is_some());
+		assert!(Staking::collator_of(2).is_none());
+		assert!(Staking::collator_of(3).is_some());
+		assert!(System::events()
+			.into_iter()
+			.any(|r| r.event == RuntimeEvent::Staking(Event::CollatorDropped { collator: 2 })));
+
+		// The under-bonded candidates will be dropped before the election.
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_eq!(<Staking as SessionManager<_>>::new_session(1), Some(vec![3]));
+
+		// Keep the current collators, if no collator could be elected.
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(3), UNIT, 0, Vec::new()));
+		assert_eq!(<Staking as SessionManager<_>>::new_session(2), None);
+		assert!(Staking::historical_exposure_of(2, 3).is_some());
+		assert!(Staking::exposure_of(3).is_some());
 	});
 }
 
@@ -536,12 +869,16 @@ fn payout_should_work() {
 			));
 			assert_ok!(Staking::nominate(RuntimeOrigin::signed(i), i - 5));
 		});
-		Staking::elect();
+		Staking::elect(0);
 		Staking::reward_by_ids(&[(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)]);
 		(1..=10).for_each(|i| assert_eq!(Balances::free_balance(i), 1_000 * UNIT));
 
 		let session_duration = Duration::new(6 * 60 * 60, 0).as_millis();
-		Staking::payout(session_duration, Staking::elapsed_time());
+		Staking::payout(0, session_duration, Staking::elapsed_time());
+		(1..=10).for_each(|i| assert_eq!(Balances::free_balance(i), 1_000 * UNIT));
+		(1..=5).for_each(|c| {
+			assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1), c, 0));
+		});
 		let rewards = [
 			1_366_118_870_124_739_965_121_u128,
 			2_550_088_512_393_184_504_844,
@@ -571,3 +908,732 @@ fn payout_should_work() {
 		);
 	});
 }
+
+#[test]
+fn nomination_split_should_work() {
+	ExtBuilder::default().collator_count(2).build().execute_with(|| {
+		(1..=2).for_each(|c| {
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(c), Default::default()));
+		});
+		(3..=4).for_each(|n| {
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(n), 2 * UNIT, 0, Vec::new()));
+		});
+		assert_ok!(Staking::nominate_multiple(
+			RuntimeOrigin::signed(3),
+			vec![1, 2],
+			vec![Perbill::from_percent(25), Perbill::from_percent(75)]
+		));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(4), 1));
+		assert_eq!(Staking::elect(0), vec![1, 2]);
+
+		let e = Staking::exposure_of(1).unwrap();
+		let mut ns = e.nominators.into_iter().map(|n| (n.who, n.value)).collect::<Vec<_>>();
+		ns.sort();
+		assert_eq!(e.total, 312_500_000);
+		assert_eq!(ns, vec![(3, 62_500_000), (4, 250_000_000)]);
+		let e = Staking::exposure_of(2).unwrap();
+		assert_eq!(e.total, 187_500_000);
+		assert_eq!(
+			e.nominators.into_iter().map(|n| (n.who, n.value)).collect::<Vec<_>>(),
+			vec![(3, 187_500_000)]
+		);
+
+		Staking::reward_by_ids(&[(1, 20), (2, 20)]);
+		let session_duration = Duration::new(6 * 60 * 60, 0).as_millis();
+		let c_payout = Perbill::from_percent(50)
+			* (<Runtime as darwinia_staking::Config>::PayoutFraction::get()
+				* dc_inflation::in_period(
+					dc_inflation::TOTAL_SUPPLY - Balances::total_issuance(),
+					session_duration,
+					Staking::elapsed_time(),
+				)
+				.unwrap());
+		Staking::payout(0, session_duration, Staking::elapsed_time());
+		(1..=2).for_each(|c| {
+			assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1), c, 0));
+		});
+		assert_eq!(
+			Balances::free_balance(3),
+			998 * UNIT + Perbill::from_percent(20) * c_payout + c_payout
+		);
+		assert_eq!(Balances::free_balance(4), 998 * UNIT + Perbill::from_percent(80) * c_payout);
+	});
+}
+
+#[test]
+fn nominator_eviction_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		(2..=5).for_each(|n| {
+			assert_ok!(Staking::stake(
+				RuntimeOrigin::signed(n),
+				n as Balance * UNIT,
+				0,
+				Vec::new()
+			));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(n), 1));
+		});
+		assert_eq!(Staking::elect(0), vec![1]);
+
+		// Only the top 3 nominators are counted.
+		let e = Staking::exposure_of(1).unwrap();
+		let mut ns = e.nominators.iter().map(|n| n.who).collect::<Vec<_>>();
+		ns.sort();
+		assert_eq!(ns, vec![3, 4, 5]);
+		assert_eq!(e.total, (3..=5).map(|n| Staking::power_of(&n)).sum::<u32>());
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::NominatorEvicted { nominator: 2, collator: 1 })));
+
+		// The evicted nominator earns no reward.
+		Staking::reward_by_ids(&[(1, 20)]);
+		Staking::payout(0, Duration::new(6 * 60 * 60, 0).as_millis(), Staking::elapsed_time());
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(1), 1, 0));
+		assert_eq!(Balances::free_balance(2), 998 * UNIT);
+		(3..=5).for_each(|n| {
+			assert!(Balances::free_balance(n) > (1_000 - n as Balance) * UNIT);
+		});
+	});
+}
+
+#[test]
+fn payout_stakers_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 0, UNIT, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(2), 0, UNIT, Vec::new()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(2), 1));
+		Staking::elect(0);
+		Staking::reward_by_ids(&[(1, 20)]);
+
+		Staking::payout(0, Duration::new(6 * 60 * 60, 0).as_millis(), Staking::elapsed_time());
+		assert_eq!(Staking::pending_reward_of(0, 1).unwrap().exposure.nominators.len(), 2);
+		let total = Staking::pending_reward_of(0, 1).unwrap().total;
+		assert_ne!(total, 0);
+
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(3), 1, 1),
+			<Error<Runtime>>::NoPendingReward
+		);
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(3), 1, 0));
+		assert!(Staking::pending_reward_of(0, 1).is_none());
+		let n_payout = Perbill::from_percent(50) * (total - Perbill::from_percent(10) * total);
+		assert_eq!(
+			Balances::free_balance(1),
+			1_000 * UNIT + Perbill::from_percent(10) * total + n_payout
+		);
+		assert_eq!(Balances::free_balance(2), 1_000 * UNIT + n_payout);
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(3), 1, 0),
+			<Error<Runtime>>::NoPendingReward
+		);
+	});
+}
+
+#[test]
+fn set_payee_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Staking::set_payee(RuntimeOrigin::signed(1), RewardDestination::Staked),
+			<Error<Runtime>>::NotStaker
+		);
+
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_eq!(Staking::payee_of(1), RewardDestination::Free);
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(1), RewardDestination::Staked));
+		assert_eq!(Staking::payee_of(1), RewardDestination::Staked);
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::PayeeSet {
+				staker: 1,
+				destination: RewardDestination::Staked
+			})));
+
+		// The reward destination will be cleaned together with the ledger.
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		Efflux::block(3);
+		assert_ok!(Staking::claim(RuntimeOrigin::signed(1)));
+		assert!(Staking::ledger_of(1).is_none());
+		assert_eq!(Staking::payee_of(1), RewardDestination::Free);
+	});
+}
+
+#[test]
+fn reward_destination_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		(1..=3).for_each(|i| {
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(i), 0, UNIT, Vec::new()));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(i), 1));
+		});
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)));
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(1), RewardDestination::Account(4)));
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(2), RewardDestination::Staked));
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(3), RewardDestination::Free));
+		Staking::elect(0);
+		Staking::reward_by_ids(&[(1, 20)]);
+		Staking::payout(0, Duration::new(6 * 60 * 60, 0).as_millis(), Staking::elapsed_time());
+
+		let r = Staking::pending_reward_of(0, 1).unwrap();
+		let c_payout = r.commission * r.total;
+		let n_payout = Perbill::from_rational(r.exposure.nominators[0].value, r.exposure.total)
+			* (r.total - c_payout);
+		let backing = Staking::total_backing_of(1);
+
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(5), 1, 0));
+		assert_eq!(Balances::free_balance(1), 1_000 * UNIT);
+		assert_eq!(Balances::free_balance(4), 1_000 * UNIT + c_payout + n_payout);
+		assert_eq!(Balances::free_balance(2), 1_000 * UNIT);
+		assert_eq!(Staking::ledger_of(2).unwrap().staked_ring, n_payout);
+		assert_eq!(Staking::total_backing_of(1).ring, backing.ring + n_payout);
+		assert_eq!(Balances::free_balance(3), 1_000 * UNIT + n_payout);
+	});
+}
+
+#[test]
+fn expire_rewards_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		Staking::elect(0);
+		Staking::reward_by_ids(&[(1, 20)]);
+		Staking::payout(0, Duration::new(6 * 60 * 60, 0).as_millis(), Staking::elapsed_time());
+		assert!(Staking::pending_reward_of(0, 1).is_some());
+
+		let total = Staking::pending_reward_of(0, 1).unwrap().total;
+
+		Staking::expire_rewards(2);
+		assert!(Staking::pending_reward_of(0, 1).is_some());
+
+		// `HistoryDepth` is 3.
+		Staking::expire_rewards(3);
+		assert!(Staking::pending_reward_of(0, 1).is_none());
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::RewardExpired { session: 0, ring_amount: total })));
+		assert_noop!(
+			Staking::payout_stakers(RuntimeOrigin::signed(1), 1, 0),
+			<Error<Runtime>>::NoPendingReward
+		);
+	});
+}
+
+#[test]
+fn history_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 0, UNIT, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(1), 1));
+		Staking::elect(0);
+		// The exposures are archived at the election.
+		assert!(Staking::historical_exposure_of(0, 1).is_some());
+		Staking::reward_by_ids(&[(1, 20)]);
+		Staking::payout(0, Duration::new(6 * 60 * 60, 0).as_millis(), Staking::elapsed_time());
+		Staking::clean_old_session(0);
+		assert_eq!(Staking::reward_points(), (0, Default::default()));
+
+		let exposure = Staking::historical_exposure_summary_of(0, &1).unwrap();
+
+		assert_eq!(exposure.total, Staking::power_of(&1));
+		assert_eq!(exposure.nominators.len(), 1);
+		assert_eq!(Staking::historical_reward_points(0), vec![(1, 20)]);
+
+		let summary = Staking::session_summary_of(0).unwrap();
+
+		assert_eq!(summary.reward_points, 20);
+		assert_eq!(summary.payout, Staking::pending_reward_of(0, 1).unwrap().total);
+		assert!(summary.inflation > summary.payout);
+
+		Staking::prune_history(2);
+		assert!(Staking::session_summary_of(0).is_some());
+
+		// `HistoryDepth` is 3.
+		Staking::prune_history(3);
+		assert!(Staking::historical_exposure_of(0, 1).is_none());
+		assert!(Staking::historical_reward_points(0).is_empty());
+		assert!(Staking::session_summary_of(0).is_none());
+	});
+}
+
+#[test]
+fn ledger_summary_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(Staking::ledger_summary_of(&1).is_none());
+
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), 100 * UNIT, 1));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 10 * UNIT, 10 * UNIT, vec![0]));
+		Efflux::block(1);
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), 0, 0, vec![0]));
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), 5 * UNIT, UNIT, Vec::new()));
+		Efflux::block(1);
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_eq!(
+			Staking::ledger_summary_of(&1).unwrap(),
+			LedgerSummary {
+				staked_ring: 4 * UNIT,
+				staked_kton: 9 * UNIT,
+				unstaking_ring: vec![(105 * UNIT, 5), (UNIT, 6)],
+				unstaking_kton: vec![(UNIT, 5)],
+			}
+		);
+	});
+}
+
+#[test]
+fn collator_ranking_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		[1, 3, 4].into_iter().enumerate().for_each(|(i, s)| {
+			let i = i as u32 + 1;
+
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(i), s * UNIT, 0, Vec::new()));
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(i), Default::default()));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(i), i));
+		});
+
+		assert_eq!(
+			Staking::collator_ranking(),
+			vec![(3, 250_000_000), (2, 187_500_000), (1, 62_500_000)]
+		);
+		assert_eq!(Staking::elect(0), vec![3]);
+	});
+}
+
+#[test]
+fn backing_index_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		(1..=2).for_each(|c| {
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(c), Default::default()));
+		});
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(3), 100 * UNIT, 1));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(3), 100 * UNIT, 10 * UNIT, vec![0]));
+		assert_ok!(Staking::nominate_multiple(
+			RuntimeOrigin::signed(3),
+			vec![1, 2],
+			vec![Perbill::from_percent(40), Perbill::from_percent(60)]
+		));
+		assert_eq!(Staking::backing_of(1, 3).unwrap(), Backing { ring: 80 * UNIT, kton: 4 * UNIT });
+		assert_eq!(
+			Staking::backing_of(2, 3).unwrap(),
+			Backing { ring: 120 * UNIT, kton: 6 * UNIT }
+		);
+
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(4), 50 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(4), 1));
+		assert_eq!(Staking::total_backing_of(1), Backing { ring: 130 * UNIT, kton: 4 * UNIT });
+
+		// Stakes change.
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(3), 0, 10 * UNIT, vec![0]));
+		assert_eq!(Staking::total_backing_of(1), Backing { ring: 90 * UNIT, kton: 0 });
+		assert_eq!(Staking::total_backing_of(2), Backing { ring: 60 * UNIT, kton: 0 });
+		assert_ok!(Staking::restake(RuntimeOrigin::signed(3), 0, 5 * UNIT, vec![0]));
+		assert_eq!(Staking::total_backing_of(1), Backing { ring: 130 * UNIT, kton: 2 * UNIT });
+		assert_eq!(Staking::total_backing_of(2), Backing { ring: 120 * UNIT, kton: 3 * UNIT });
+
+		// Nominations change.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(3), 2));
+		assert!(Staking::backing_of(1, 3).is_none());
+		assert_eq!(Staking::total_backing_of(1), Backing { ring: 50 * UNIT, kton: 0 });
+		assert_eq!(Staking::total_backing_of(2), Backing { ring: 200 * UNIT, kton: 5 * UNIT });
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(3)));
+		assert!(Staking::backing_of(2, 3).is_none());
+		assert_eq!(Staking::total_backing_of(2), Backing::default());
+	});
+}
+
+#[test]
+fn extend_and_relock_should_refresh_backings() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(2), 100 * UNIT, 1));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(2), 0, 0, vec![0]));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Staking::backing_of(1, 2).unwrap(), Backing { ring: 100 * UNIT, kton: 0 });
+
+		// The power of the deposit depends on the power calculator.
+		DepositPowerBonus::set(Perbill::from_percent(50));
+		assert_ok!(Deposit::extend(RuntimeOrigin::signed(2), 0, 1));
+		assert_eq!(Staking::backing_of(1, 2).unwrap(), Backing { ring: 150 * UNIT, kton: 0 });
+
+		DepositPowerBonus::set(Perbill::one());
+		Efflux::time(2 * darwinia_deposit::MILLISECS_PER_MONTH);
+		assert_ok!(Deposit::relock(RuntimeOrigin::signed(2), 0, 1));
+		assert_eq!(Staking::backing_of(1, 2).unwrap(), Backing { ring: 200 * UNIT, kton: 0 });
+		assert_eq!(Staking::total_backing_of(1), Backing { ring: 200 * UNIT, kton: 0 });
+	});
+}
+
+#[test]
+fn set_collator_count_should_work() {
+	ExtBuilder::default().collator_count(3).build().execute_with(|| {
+		assert_noop!(
+			Staking::set_collator_count(RuntimeOrigin::signed(1), 5),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Staking::set_collator_count(RuntimeOrigin::root(), 5));
+		assert_eq!(Staking::collator_count(), 5);
+
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![1, 2]));
+		assert_noop!(
+			Staking::set_collator_count(RuntimeOrigin::root(), 1),
+			<Error<Runtime>>::ExceedCollatorCount
+		);
+	});
+}
+
+#[test]
+fn invulnerables_should_work() {
+	ExtBuilder::default().collator_count(3).build().execute_with(|| {
+		(1..=5).for_each(|i| {
+			assert_ok!(Staking::stake(
+				RuntimeOrigin::signed(i),
+				i as Balance * UNIT,
+				0,
+				Vec::new()
+			));
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(i), Default::default()));
+			assert_ok!(Staking::nominate(RuntimeOrigin::signed(i), i));
+		});
+		assert_eq!(Staking::elect(0), vec![5, 4, 3]);
+
+		assert_noop!(
+			Staking::set_invulnerables(RuntimeOrigin::signed(1), vec![1]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Staking::set_invulnerables(RuntimeOrigin::root(), vec![1, 2, 6, 7]),
+			<Error<Runtime>>::ExceedCollatorCount
+		);
+
+		// The duplicated ones will be removed.
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![2, 1, 1]));
+		assert_eq!(Staking::invulnerables(), vec![1, 2]);
+		assert_eq!(Staking::elect(0), vec![1, 2, 5]);
+
+		// The invulnerables will be elected, even if they are not the candidates.
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![4, 6]));
+		assert_eq!(Staking::elect(0), vec![4, 6, 5]);
+	});
+}
+
+#[test]
+fn invulnerables_payout_should_work() {
+	ExtBuilder::default().collator_count(2).build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![2]));
+		assert_eq!(Staking::elect(0), vec![2, 1]);
+		assert!(Staking::collator_of(2).is_none());
+
+		Staking::reward_by_ids(&[(1, 20), (2, 20)]);
+		Staking::payout(0, Duration::new(6 * 60 * 60, 0).as_millis(), Staking::elapsed_time());
+
+		// The invulnerable which has never collected takes the whole reward.
+		let r = Staking::pending_reward_of(0, 2).unwrap();
+		assert_eq!(r.commission, Perbill::one());
+		assert_ne!(r.total, 0);
+		assert_ok!(Staking::payout_stakers(RuntimeOrigin::signed(3), 2, 0));
+		assert_eq!(Balances::free_balance(2), 1_000 * UNIT + r.total);
+	});
+}
+
+#[test]
+fn chill_inactive_collators_should_work() {
+	ExtBuilder::default().collator_count(3).build().execute_with(|| {
+		MinAuthoredBlocks::set(1);
+		(1..=3).for_each(|i| {
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(i), UNIT, 0, Vec::new()));
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(i), Default::default()));
+		});
+		assert_ok!(Staking::set_invulnerables(RuntimeOrigin::root(), vec![3]));
+
+		// The collators of the ending session are checked, while the next ones have been elected.
+		let end_session = |index, authors: &[u32]| {
+			authors.iter().for_each(|a| <AuthoredBlocks<Runtime>>::insert(a, 1));
+			Staking::chill_inactive_collators(index);
+			Staking::elect(index + 2);
+		};
+
+		assert_eq!(Staking::elect(0), vec![3, 1, 2]);
+		Staking::elect(1);
+		end_session(0, &[1]);
+		assert_eq!(Staking::inactive_sessions_of(1), 0);
+		assert_eq!(Staking::inactive_sessions_of(2), 1);
+		assert_eq!(Staking::inactive_sessions_of(3), 0);
+		assert_eq!(Staking::authored_blocks_of(1), 0);
+
+		// An active session resets the count.
+		end_session(1, &[1, 2]);
+		assert_eq!(Staking::inactive_sessions_of(2), 0);
+
+		// A collator which is only elected for the upcoming sessions isn't checked.
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(4), 2 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(4), Default::default()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(4), 4));
+		end_session(2, &[]);
+		assert_eq!(Staking::exposure_of(4).map(|e| e.total), Some(Staking::power_of(&4)));
+		assert!(Staking::historical_exposure_of(3, 4).is_none());
+		assert!(Staking::collator_of(1).is_some());
+		assert!(Staking::collator_of(2).is_some());
+
+		// `MaxInactiveSessions` is 2.
+		end_session(3, &[]);
+		assert_eq!(Staking::inactive_sessions_of(4), 0);
+		assert!(Staking::collator_of(4).is_some());
+		assert!(Staking::collator_of(1).is_none());
+		assert!(Staking::collator_of(2).is_none());
+		assert_eq!(Staking::inactive_sessions_of(1), 0);
+		assert!(System::events().into_iter().any(|r| r.event
+			== RuntimeEvent::Staking(Event::InactiveCollatorChilled {
+				collator: 1,
+				inactive_sessions: 2
+			})));
+		assert!(Staking::ledger_of(1).is_some());
+
+		// The invulnerables are never chilled.
+		assert!(Staking::collator_of(3).is_some());
+		assert_eq!(Staking::inactive_sessions_of(3), 0);
+	});
+}
+
+#[test]
+fn force_chill_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(1), 1));
+		assert_noop!(Staking::force_chill(RuntimeOrigin::signed(1), 1), DispatchError::BadOrigin);
+
+		assert_ok!(Staking::force_chill(RuntimeOrigin::root(), 1));
+		assert!(Staking::collator_of(1).is_none());
+		assert!(Staking::nominator_of(1).is_none());
+		assert_eq!(Staking::total_backing_of(1), Backing::default());
+		assert!(Staking::ledger_of(1).is_some());
+	});
+}
+
+#[test]
+fn force_unstake_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Staking::force_unstake(RuntimeOrigin::root(), 1), <Error<Runtime>>::NotStaker);
+
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(1), UNIT, 1));
+		let kton_balance = Assets::balance(0, 1);
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 2 * UNIT, 2 * UNIT, vec![0, 1]));
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(1), RewardDestination::Staked));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(1), UNIT, UNIT, vec![1]));
+		assert_eq!(System::account(1).consumers, 2);
+		assert_noop!(Staking::force_unstake(RuntimeOrigin::signed(1), 1), DispatchError::BadOrigin);
+
+		assert_ok!(Staking::force_unstake(RuntimeOrigin::root(), 1));
+		assert!(Staking::ledger_of(1).is_none());
+		assert!(Staking::collator_of(1).is_none());
+		assert!(Staking::nominator_of(1).is_none());
+		assert_eq!(Staking::payee_of(1), RewardDestination::Free);
+		assert_eq!(Staking::ring_pool(), 0);
+		assert_eq!(Staking::kton_pool(), 0);
+		assert_eq!(System::account(1).consumers, 1);
+		assert_eq!(Balances::free_balance(1), 998 * UNIT);
+		assert_eq!(Assets::balance(0, 1), kton_balance);
+		assert!(Deposit::deposit_of(1).unwrap().into_iter().all(|d| !d.in_use));
+	});
+}
+
+#[test]
+fn force_unstake_should_remove_chilled_commission() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(10)));
+
+		assert_ok!(Staking::force_unstake(RuntimeOrigin::root(), 1));
+		assert!(Staking::ledger_of(1).is_none());
+		assert!(Staking::chilled_commission_of(1).is_none());
+
+		// A new ledger starts without the previous commission.
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Perbill::from_percent(50)));
+		assert_eq!(Staking::collator_of(1), Some(Perbill::from_percent(50)));
+		assert!(Staking::pending_commission_of(1).is_none());
+	});
+}
+
+fn report_offence(offender: u32, fraction: Perbill, session: u32) {
+	<Staking as OnOffenceHandler<_, _, _>>::on_offence(
+		&[OffenceDetails { offender, reporters: Vec::new() }],
+		&[fraction],
+		session,
+		DisableStrategy::Never,
+	);
+}
+
+#[test]
+fn slash_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 100 * UNIT, 100 * UNIT, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Deposit::lock(RuntimeOrigin::signed(2), 100 * UNIT, 1));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(2), 100 * UNIT, 0, vec![0]));
+		assert_ok!(Staking::unstake(RuntimeOrigin::signed(2), 50 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(2), 1));
+		assert_eq!(Staking::elect(0), vec![1]);
+		assert_eq!(Staking::ring_pool(), 250 * UNIT);
+		assert_eq!(Staking::kton_pool(), 100 * UNIT);
+
+		// Deferred.
+		report_offence(1, Perbill::from_percent(10), 0);
+		assert_eq!(Staking::unapplied_slashes(1).len(), 1);
+		assert_eq!(Staking::ledger_of(1).unwrap().staked_ring, 100 * UNIT);
+
+		Staking::apply_unapplied_slashes(1);
+		assert!(Staking::unapplied_slashes(1).is_empty());
+		assert_eq!(
+			Staking::ledger_of(1).unwrap(),
+			Ledger { staked_ring: 90 * UNIT, staked_kton: 90 * UNIT, ..ZeroDefault::default() }
+		);
+		assert_eq!(
+			Staking::ledger_of(2).unwrap(),
+			Ledger {
+				staked_ring: 45 * UNIT,
+				staked_deposits: BoundedVec::truncate_from(vec![0]),
+				..ZeroDefault::default()
+			}
+		);
+		assert_eq!(unstakings_of(2), vec![(4, 45 * UNIT, 0, Vec::new())]);
+		assert_eq!(Deposit::deposit_of(2).unwrap()[0].value, 90 * UNIT);
+		assert_eq!(Staking::ring_pool(), 225 * UNIT);
+		assert_eq!(Staking::kton_pool(), 90 * UNIT);
+		assert_eq!(Balances::free_balance(darwinia_staking::account_id::<u32>()), 180 * UNIT);
+		assert_eq!(Assets::balance(0, darwinia_staking::account_id::<u32>()), 90 * UNIT);
+	});
+}
+
+#[test]
+fn slash_fraction_should_be_capped() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 100 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+
+		report_offence(1, Perbill::from_percent(100), 0);
+		Staking::apply_unapplied_slashes(1);
+		assert_eq!(Staking::ledger_of(1).unwrap().staked_ring, 50 * UNIT);
+	});
+}
+
+#[test]
+fn slash_should_only_take_the_backing_share() {
+	ExtBuilder::default().collator_count(2).build().execute_with(|| {
+		(1..=2).for_each(|i| {
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(i), 100 * UNIT, 0, Vec::new()));
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(i), Default::default()));
+		});
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(3), 100 * UNIT, 100 * UNIT, Vec::new()));
+		assert_ok!(Staking::nominate_multiple(
+			RuntimeOrigin::signed(3),
+			vec![1, 2],
+			vec![Perbill::from_percent(40), Perbill::from_percent(60)]
+		));
+		Staking::elect(0);
+
+		report_offence(1, Perbill::from_percent(10), 0);
+		Staking::apply_unapplied_slashes(1);
+		assert_eq!(Staking::ledger_of(1).unwrap().staked_ring, 90 * UNIT);
+		assert_eq!(Staking::ledger_of(2).unwrap().staked_ring, 100 * UNIT);
+		// Only the 40% which backs the offender is slashed.
+		assert_eq!(
+			Staking::ledger_of(3).unwrap(),
+			Ledger { staked_ring: 96 * UNIT, staked_kton: 96 * UNIT, ..ZeroDefault::default() }
+		);
+
+		// The nominator has stopped nominating the offender since the election.
+		//
+		// Fall back to the share of the exposed power.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(3), 2));
+		report_offence(1, Perbill::from_percent(10), 0);
+		Staking::apply_unapplied_slashes(1);
+
+		let staked_ring = Staking::ledger_of(3).unwrap().staked_ring;
+
+		assert!(staked_ring < 96 * UNIT);
+		assert!(staked_ring > 96 * UNIT - 96 * UNIT / 10);
+	});
+}
+
+#[test]
+fn slash_should_take_the_exposure_of_the_offence_session() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 100 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(2), 100 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(2), 1));
+		Staking::elect(0);
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(2)));
+		Staking::elect(1);
+
+		// The nominator doesn't back the offender in the session 1.
+		report_offence(1, Perbill::from_percent(10), 1);
+		Staking::apply_unapplied_slashes(2);
+		assert_eq!(Staking::ledger_of(1).unwrap().staked_ring, 90 * UNIT);
+		assert_eq!(Staking::ledger_of(2).unwrap().staked_ring, 100 * UNIT);
+
+		// But it does in the session 0.
+		report_offence(1, Perbill::from_percent(10), 0);
+		Staking::apply_unapplied_slashes(1);
+		assert!(Staking::ledger_of(2).unwrap().staked_ring < 100 * UNIT);
+	});
+}
+
+#[test]
+fn inactive_collators_should_be_slashed() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		MinAuthoredBlocks::set(1);
+		InactivitySlashFraction::set(Perbill::from_percent(10));
+		assert_ok!(Staking::stake(RuntimeOrigin::signed(1), 100 * UNIT, 0, Vec::new()));
+		assert_ok!(Staking::collect(RuntimeOrigin::signed(1), Default::default()));
+		Staking::elect(0);
+		Staking::elect(1);
+
+		// `MaxInactiveSessions` is 2.
+		Staking::chill_inactive_collators(0);
+		assert!(Staking::unapplied_slashes(1).is_empty());
+		Staking::chill_inactive_collators(1);
+		assert!(Staking::collator_of(1).is_none());
+		assert_eq!(Staking::unapplied_slashes(2).len(), 1);
+
+		Staking::apply_unapplied_slashes(2);
+		assert_eq!(Staking::ledger_of(1).unwrap().staked_ring, 90 * UNIT);
+	});
+}
+
+#[test]
+fn cancel_deferred_slash_should_work() {
+	ExtBuilder::default().collator_count(1).build().execute_with(|| {
+		(1..=2).for_each(|i| {
+			assert_ok!(Staking::stake(RuntimeOrigin::signed(i), 100 * UNIT, 0, Vec::new()));
+			assert_ok!(Staking::collect(RuntimeOrigin::signed(i), Default::default()));
+			report_offence(i, Perbill::from_percent(10), 0);
+		});
+		assert_eq!(Staking::unapplied_slashes(1).len(), 2);
+
+		assert_noop!(
+			Staking::cancel_deferred_slash(RuntimeOrigin::signed(1), 1, vec![0]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Staking::cancel_deferred_slash(RuntimeOrigin::root(), 1, Vec::new()),
+			<Error<Runtime>>::EmptySlashIndices
+		);
+		assert_noop!(
+			Staking::cancel_deferred_slash(RuntimeOrigin::root(), 1, vec![2]),
+			<Error<Runtime>>::InvalidSlashIndex
+		);
+
+		assert_ok!(Staking::cancel_deferred_slash(RuntimeOrigin::root(), 1, vec![1]));
+		assert_eq!(Staking::unapplied_slashes(1).len(), 1);
+
+		Staking::apply_unapplied_slashes(1);
+		assert_eq!(Staking::ledger_of(1).unwrap().staked_ring, 90 * UNIT);
+		assert_eq!(Staking::ledger_of(2).unwrap().staked_ring, 100 * UNIT);
+	});
+}
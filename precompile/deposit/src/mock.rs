@@ -106,11 +106,17 @@ impl darwinia_deposit::SimpleAsset for KtonAsset {
 }
 
 impl darwinia_deposit::Config for TestRuntime {
+	type DepositInterest = ();
 	type Kton = KtonAsset;
+	type LinearVesting = frame_support::traits::ConstBool<false>;
 	type MaxDeposits = frame_support::traits::ConstU32<16>;
+	type MaxLockingMonths = frame_support::traits::ConstU8<36>;
 	type MinLockingAmount = frame_support::traits::ConstU128<100>;
+	type MinLockingMonths = frame_support::traits::ConstU8<1>;
+	type PenaltyMultiplier = frame_support::traits::ConstU32<3>;
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type Staking = ();
 	type WeightInfo = ();
 }
 
@@ -20,7 +20,7 @@
 use crate::mock::{
 	efflux,
 	Account::{Alice, Precompile},
-	Deposit, ExtBuilder, PCall, PrecompilesValue, TestPrecompiles, TestRuntime,
+	Balances, Deposit, ExtBuilder, PCall, PrecompilesValue, TestPrecompiles, TestRuntime,
 };
 use darwinia_deposit::MILLISECS_PER_MONTH;
 // moonbeam
@@ -67,11 +67,13 @@ fn claim_with_penalty() {
 			.prepare_test(alice, Precompile, PCall::lock { amount: 200.into(), months: 1 })
 			.execute_returns(EvmDataWriter::new().write(true).build());
 		assert!(Deposit::deposit_of(&alice).is_some());
+		assert_eq!(Balances::free_balance(&alice), 100);
 
 		// claim with penalty
 		precompiles()
 			.prepare_test(alice, Precompile, PCall::claim_with_penalty { id: 0 })
 			.execute_returns(EvmDataWriter::new().write(true).build());
 		assert!(Deposit::deposit_of(&alice).is_none());
+		assert_eq!(Balances::free_balance(&alice), 300);
 	});
 }
@@ -0,0 +1,206 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+// core
+use core::marker::PhantomData;
+// crates.io
+use codec::{Decode, DecodeLimit};
+// moonbeam
+use precompile_utils::prelude::*;
+// substrate
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	traits::{Get, InstanceFilter, IsSubType, OriginTrait},
+};
+use sp_core::H160;
+use sp_runtime::traits::StaticLookup;
+use sp_std::prelude::*;
+
+/// The maximum depth while decoding the proxied call.
+pub const CALL_DECODE_DEPTH_LIMIT: u32 = 8;
+
+/// AccountId of the runtime.
+type AccountIdOf<R> = <R as frame_system::pallet::Config>::AccountId;
+/// RuntimeCall of the proxy pallet.
+type ProxiedCallOf<R> = <R as pallet_proxy::Config>::RuntimeCall;
+
+// Set while the `proxy` is dispatching, to reject the re-entrant calls.
+environmental::environmental!(PROXYING: bool);
+
+/// The proxy precompile.
+///
+/// Only the proxies of the `ForcedProxyType` could dispatch calls through the `proxy`.
+pub struct Proxy<Runtime, ForcedProxyType>(PhantomData<(Runtime, ForcedProxyType)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, ForcedProxyType> Proxy<Runtime, ForcedProxyType>
+where
+	Runtime: pallet_proxy::Config + pallet_evm::Config + pallet_ethereum::Config,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_proxy::Call<Runtime>>,
+	ProxiedCallOf<Runtime>:
+		IsSubType<pallet_evm::Call<Runtime>> + IsSubType<pallet_ethereum::Call<Runtime>>,
+	ForcedProxyType: Get<Runtime::ProxyType>,
+	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+	AccountIdOf<Runtime>: From<H160>,
+{
+	/// Register `delegate` as a proxy of the caller.
+	///
+	/// Only the `ForcedProxyType` could be registered, the other types can't be used through the
+	/// `proxy` anyway.
+	#[precompile::public("addProxy(address,uint8,uint32)")]
+	fn add_proxy(
+		handle: &mut impl PrecompileHandle,
+		delegate: Address,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult<bool> {
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+		let delegate: AccountIdOf<Runtime> = H160::from(delegate).into();
+		let proxy_type = Self::proxy_type_of(proxy_type)?;
+
+		if proxy_type != ForcedProxyType::get() {
+			return Err(revert("Proxy type not allowed"));
+		}
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_proxy::Call::<Runtime>::add_proxy {
+				delegate: Runtime::Lookup::unlookup(delegate),
+				proxy_type,
+				delay: delay.into(),
+			},
+		)?;
+
+		Ok(true)
+	}
+
+	/// Unregister the `delegate` proxy of the caller.
+	///
+	/// Any type is accepted, since removing a proxy never grants a permission. So, the proxies
+	/// registered outside the EVM could be removed here too.
+	#[precompile::public("removeProxy(address,uint8,uint32)")]
+	fn remove_proxy(
+		handle: &mut impl PrecompileHandle,
+		delegate: Address,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult<bool> {
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+		let delegate: AccountIdOf<Runtime> = H160::from(delegate).into();
+		let proxy_type = Self::proxy_type_of(proxy_type)?;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			pallet_proxy::Call::<Runtime>::remove_proxy {
+				delegate: Runtime::Lookup::unlookup(delegate),
+				proxy_type,
+				delay: delay.into(),
+			},
+		)?;
+
+		Ok(true)
+	}
+
+	/// Dispatch the SCALE encoded runtime `call` on behalf of `real`.
+	///
+	/// The caller must be a `ForcedProxyType` proxy of `real` without any announcement delay.
+	/// The calls into the EVM and the re-entrant calls are rejected.
+	#[precompile::public("proxy(address,bytes)")]
+	fn proxy(
+		handle: &mut impl PrecompileHandle,
+		real: Address,
+		call: UnboundedBytes,
+	) -> EvmResult<bool> {
+		if PROXYING::with(|p| *p).unwrap_or_default() {
+			return Err(revert("Re-entrant proxy call"));
+		}
+
+		let origin: AccountIdOf<Runtime> = handle.context().caller.into();
+		let real: AccountIdOf<Runtime> = H160::from(real).into();
+		let call = <ProxiedCallOf<Runtime>>::decode_with_depth_limit(
+			CALL_DECODE_DEPTH_LIMIT,
+			&mut call.as_bytes(),
+		)
+		.map_err(|_| revert("Invalid call"))?;
+
+		if IsSubType::<pallet_evm::Call<Runtime>>::is_sub_type(&call).is_some()
+			|| IsSubType::<pallet_ethereum::Call<Runtime>>::is_sub_type(&call).is_some()
+		{
+			return Err(revert("EVM call"));
+		}
+
+		let proxy_type = ForcedProxyType::get();
+
+		// The proxy pallet only reports the filtered call in an event.
+		if !proxy_type.filter(&call) {
+			return Err(revert("Call not allowed"));
+		}
+
+		PROXYING::using(&mut true, || {
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				Some(origin).into(),
+				pallet_proxy::Call::<Runtime>::proxy {
+					real: Runtime::Lookup::unlookup(real),
+					force_proxy_type: Some(proxy_type),
+					call: Box::new(call),
+				},
+			)
+		})?;
+
+		Ok(true)
+	}
+
+	/// Check if `delegate` is a proxy of `real` with the given type and delay.
+	#[precompile::public("isProxy(address,address,uint8,uint32)")]
+	#[precompile::view]
+	fn is_proxy(
+		handle: &mut impl PrecompileHandle,
+		real: Address,
+		delegate: Address,
+		proxy_type: u8,
+		delay: u32,
+	) -> EvmResult<bool> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let real: AccountIdOf<Runtime> = H160::from(real).into();
+		let delegate: AccountIdOf<Runtime> = H160::from(delegate).into();
+		let proxy_type = Self::proxy_type_of(proxy_type)?;
+		let delay = delay.into();
+
+		Ok(<pallet_proxy::Pallet<Runtime>>::proxies(real)
+			.0
+			.iter()
+			.any(|d| d.delegate == delegate && d.proxy_type == proxy_type && d.delay == delay))
+	}
+
+	fn proxy_type_of(proxy_type: u8) -> EvmResult<Runtime::ProxyType> {
+		Runtime::ProxyType::decode(&mut &[proxy_type][..]).map_err(|_| revert("Invalid proxy type"))
+	}
+}
@@ -0,0 +1,262 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+// core
+use core::str::from_utf8;
+// crates.io
+use codec::Encode;
+// darwinia
+use crate::{
+	mock::{
+		Account::{Alice, Bob, Charlie, Precompile},
+		Balances, ExtBuilder, PCall, PrecompilesValue, Proxy, ProxyType, RuntimeCall,
+		RuntimeOrigin, TestPrecompiles, TestRuntime,
+	},
+	PROXYING,
+};
+// moonbeam
+use precompile_utils::{prelude::Address, testing::PrecompileTesterExt, EvmDataWriter};
+// substrate
+use frame_support::assert_ok;
+use sp_core::H160;
+
+fn precompiles() -> TestPrecompiles<TestRuntime> {
+	PrecompilesValue::get()
+}
+
+fn transfer_call(dest: H160, value: u128) -> Vec<u8> {
+	RuntimeCall::Balances(pallet_balances::Call::transfer { dest, value }).encode()
+}
+
+#[test]
+fn selectors() {
+	assert!(PCall::add_proxy_selectors().contains(&0x74a34dd3));
+	assert!(PCall::remove_proxy_selectors().contains(&0xfef3f708));
+	assert!(PCall::proxy_selectors().contains(&0xbe6d055a));
+	assert!(PCall::is_proxy_selectors().contains(&0xe26d38ed));
+}
+
+#[test]
+fn add_and_remove_proxy() {
+	let (alice, bob): (H160, H160) = (Alice.into(), Bob.into());
+	ExtBuilder::default().with_balances(vec![(alice, 300)]).build().execute_with(|| {
+		let is_proxy = |r| {
+			precompiles()
+				.prepare_test(
+					alice,
+					Precompile,
+					PCall::is_proxy {
+						real: Address(alice),
+						delegate: Address(bob),
+						proxy_type: ProxyType::Transfer as u8,
+						delay: 0,
+					},
+				)
+				.execute_returns(EvmDataWriter::new().write(r).build());
+		};
+
+		is_proxy(false);
+
+		// add proxy
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::add_proxy {
+					delegate: Address(bob),
+					proxy_type: ProxyType::Transfer as u8,
+					delay: 0,
+				},
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		is_proxy(true);
+
+		// remove proxy
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::remove_proxy {
+					delegate: Address(bob),
+					proxy_type: ProxyType::Transfer as u8,
+					delay: 0,
+				},
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		is_proxy(false);
+
+		// invalid proxy type
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::add_proxy { delegate: Address(bob), proxy_type: 2, delay: 0 },
+			)
+			.execute_reverts(|output| output == b"Invalid proxy type");
+
+		// not the forced proxy type
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::add_proxy {
+					delegate: Address(bob),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.execute_reverts(|output| output == b"Proxy type not allowed");
+		is_proxy(false);
+
+		// remove a proxy registered outside the EVM
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(alice), bob, ProxyType::Any, 0));
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::remove_proxy {
+					delegate: Address(bob),
+					proxy_type: ProxyType::Any as u8,
+					delay: 0,
+				},
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		assert!(Proxy::proxies(alice).0.is_empty());
+	});
+}
+
+#[test]
+fn proxy() {
+	let (alice, bob, charlie): (H160, H160, H160) = (Alice.into(), Bob.into(), Charlie.into());
+	ExtBuilder::default().with_balances(vec![(alice, 300)]).build().execute_with(|| {
+		// not a proxy
+		precompiles()
+			.prepare_test(
+				bob,
+				Precompile,
+				PCall::proxy { real: Address(alice), call: transfer_call(charlie, 100).into() },
+			)
+			.execute_reverts(|output| {
+				from_utf8(output).unwrap().contains("Dispatched call failed with error: ")
+					&& from_utf8(output).unwrap().contains("NotProxy")
+			});
+
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::add_proxy {
+					delegate: Address(bob),
+					proxy_type: ProxyType::Transfer as u8,
+					delay: 0,
+				},
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		// proxy a transfer
+		precompiles()
+			.prepare_test(
+				bob,
+				Precompile,
+				PCall::proxy { real: Address(alice), call: transfer_call(charlie, 100).into() },
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		assert_eq!(Balances::free_balance(alice), 200);
+		assert_eq!(Balances::free_balance(charlie), 100);
+
+		// invalid call
+		precompiles()
+			.prepare_test(
+				bob,
+				Precompile,
+				PCall::proxy { real: Address(alice), call: vec![0xff].into() },
+			)
+			.execute_reverts(|output| output == b"Invalid call");
+	});
+}
+
+#[test]
+fn proxy_restrictions() {
+	let (alice, bob, charlie): (H160, H160, H160) = (Alice.into(), Bob.into(), Charlie.into());
+	ExtBuilder::default().with_balances(vec![(alice, 300)]).build().execute_with(|| {
+		// not the forced proxy type
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(alice), bob, ProxyType::Any, 0));
+		precompiles()
+			.prepare_test(
+				bob,
+				Precompile,
+				PCall::proxy { real: Address(alice), call: transfer_call(charlie, 100).into() },
+			)
+			.execute_reverts(|output| from_utf8(output).unwrap().contains("NotProxy"));
+
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::add_proxy {
+					delegate: Address(bob),
+					proxy_type: ProxyType::Transfer as u8,
+					delay: 0,
+				},
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		// not allowed by the forced proxy type
+		precompiles()
+			.prepare_test(
+				bob,
+				Precompile,
+				PCall::proxy {
+					real: Address(alice),
+					call: RuntimeCall::System(frame_system::Call::remark { remark: Vec::new() })
+						.encode()
+						.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"Call not allowed");
+
+		// call into the EVM
+		precompiles()
+			.prepare_test(
+				bob,
+				Precompile,
+				PCall::proxy {
+					real: Address(alice),
+					call: RuntimeCall::EVM(pallet_evm::Call::withdraw {
+						address: alice,
+						value: 100,
+					})
+					.encode()
+					.into(),
+				},
+			)
+			.execute_reverts(|output| output == b"EVM call");
+
+		// re-entrant call
+		PROXYING::using(&mut true, || {
+			precompiles()
+				.prepare_test(
+					bob,
+					Precompile,
+					PCall::proxy { real: Address(alice), call: transfer_call(charlie, 100).into() },
+				)
+				.execute_reverts(|output| output == b"Re-entrant proxy call");
+		});
+		assert_eq!(Balances::free_balance(alice), 300);
+	});
+}
@@ -26,7 +26,7 @@ mod tests;
 // core
 use core::marker::PhantomData;
 // darwinia
-use darwinia_staking::Stake;
+use darwinia_staking::{RewardDestination, Stake};
 // moonbeam
 use precompile_utils::prelude::*;
 // substrate
@@ -50,7 +50,7 @@ where
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
 	<Runtime as frame_system::Config>::AccountId: From<H160>,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
-	<<Runtime as darwinia_staking::Config>::Deposit as Stake>::Item: From<u8>,
+	<<Runtime as darwinia_staking::Config>::Deposit as Stake>::Item: From<u8> + Into<u16>,
 {
 	#[precompile::public("stake(uint256,uint256,uint8[])")]
 	fn stake(
@@ -118,6 +118,33 @@ where
 		Ok(true)
 	}
 
+	/// Lock the RING for `months` and stake the new deposit.
+	///
+	/// Return the id of the new deposit.
+	#[precompile::public("lockAndStake(uint256,uint8)")]
+	fn lock_and_stake(
+		handle: &mut impl PrecompileHandle,
+		amount: U256,
+		months: u8,
+	) -> EvmResult<u16> {
+		let caller = handle.context().caller;
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(caller.into()).into(),
+			darwinia_staking::Call::<Runtime>::lock_and_stake { amount: amount.as_u128(), months },
+		)?;
+
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// The new deposit is always pushed to the end of the staked deposits.
+		darwinia_staking::Pallet::<Runtime>::ledger_of(
+			<Runtime as frame_system::Config>::AccountId::from(caller),
+		)
+		.and_then(|l| l.staked_deposits.last().map(|d| (*d).into()))
+		.ok_or_else(|| revert("Deposit not found"))
+	}
+
 	#[precompile::public("claim()")]
 	fn claim(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
 		let origin = handle.context().caller.into();
@@ -144,6 +171,27 @@ where
 		Ok(true)
 	}
 
+	/// Return the pending commission increase of the collator, in percent, along with the session
+	/// to be applied at.
+	///
+	/// Return `(0, 0)` if there is no pending change.
+	#[precompile::public("pendingCommissionOf(address)")]
+	#[precompile::view]
+	fn pending_commission_of(
+		handle: &mut impl PrecompileHandle,
+		collator: Address,
+	) -> EvmResult<(u32, u32)> {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		let collator: H160 = collator.into();
+
+		Ok(darwinia_staking::Pallet::<Runtime>::pending_commission_of(
+			<Runtime as frame_system::Config>::AccountId::from(collator),
+		)
+		.map(|(c, s)| (c.deconstruct() / 10_000_000, s))
+		.unwrap_or_default())
+	}
+
 	#[precompile::public("nominate(address)")]
 	fn nominate(handle: &mut impl PrecompileHandle, target: Address) -> EvmResult<bool> {
 		let target: H160 = target.into();
@@ -157,6 +205,29 @@ where
 		Ok(true)
 	}
 
+	#[precompile::public("setPayee(uint8,address)")]
+	fn set_payee(
+		handle: &mut impl PrecompileHandle,
+		destination: u8,
+		account: Address,
+	) -> EvmResult<bool> {
+		let account: H160 = account.into();
+		let origin = handle.context().caller.into();
+		let destination = match destination {
+			0 => RewardDestination::Account(account.into()),
+			1 => RewardDestination::Staked,
+			2 => RewardDestination::Free,
+			_ => return Err(revert("Invalid reward destination")),
+		};
+
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			Some(origin).into(),
+			darwinia_staking::Call::<Runtime>::set_payee { destination },
+		)?;
+		Ok(true)
+	}
+
 	#[precompile::public("chill()")]
 	fn chill(handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
 		let origin = handle.context().caller.into();
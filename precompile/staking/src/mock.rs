@@ -108,11 +108,17 @@ impl darwinia_deposit::SimpleAsset for KtonAsset {
 }
 
 impl darwinia_deposit::Config for TestRuntime {
+	type DepositInterest = ();
 	type Kton = KtonAsset;
+	type LinearVesting = frame_support::traits::ConstBool<false>;
 	type MaxDeposits = frame_support::traits::ConstU32<16>;
+	type MaxLockingMonths = frame_support::traits::ConstU8<36>;
 	type MinLockingAmount = frame_support::traits::ConstU128<100>;
+	type MinLockingMonths = frame_support::traits::ConstU8<1>;
+	type PenaltyMultiplier = frame_support::traits::ConstU32<3>;
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type Staking = Staking;
 	type WeightInfo = ();
 }
 
@@ -179,6 +185,8 @@ impl pallet_evm::Config for TestRuntime {
 
 frame_support::parameter_types! {
 	pub const PayoutFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(40);
+	pub const MaxSlashFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
+	pub const MaxCommission: sp_runtime::Perbill = sp_runtime::Perbill::one();
 }
 
 pub enum RingStaking {}
@@ -204,6 +212,21 @@ impl darwinia_staking::Stake for RingStaking {
 		)
 	}
 }
+impl darwinia_staking::Slash for RingStaking {
+	fn slash(
+		_: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let amount = fraction * item;
+		let (_, unslashed) = <Balances as frame_support::traits::Currency<_>>::slash(
+			&darwinia_staking::account_id(),
+			amount,
+		);
+
+		Ok(amount - unslashed)
+	}
+}
 
 pub enum KtonStaking {}
 impl darwinia_staking::Stake for KtonStaking {
@@ -218,19 +241,45 @@ impl darwinia_staking::Stake for KtonStaking {
 		Ok(())
 	}
 }
+impl darwinia_staking::Slash for KtonStaking {
+	fn slash(
+		_who: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		Ok(fraction * item)
+	}
+}
 
 impl darwinia_staking::Config for TestRuntime {
+	type CommissionChangeDelay = frame_support::traits::ConstU32<1>;
 	type Deposit = Deposit;
+	type HistoryDepth = frame_support::traits::ConstU32<120>;
+	type InactivitySlashFraction = ();
+	type KeysRegistry = frame_support::traits::Everything;
 	type Kton = KtonStaking;
+	type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxCommission = MaxCommission;
+	type MaxCommissionDelta = MaxCommission;
 	type MaxDeposits = frame_support::traits::ConstU32<16>;
-	type MaxUnstakings = frame_support::traits::ConstU32<16>;
+	type MaxInactiveSessions = frame_support::traits::ConstU32<2>;
+	type MaxNominations = frame_support::traits::ConstU32<16>;
+	type MaxNominatorsPerCollator = frame_support::traits::ConstU32<32>;
+	type MaxSlashFraction = MaxSlashFraction;
+	type MinAuthoredBlocks = frame_support::traits::ConstU32<0>;
+	type MinCollatorStake = frame_support::traits::ConstU128<0>;
 	type MinStakingDuration = frame_support::traits::ConstU64<3>;
+	type NextSessionRotation = ();
 	type PayoutFraction = PayoutFraction;
+	type PowerCalculator = ();
 	type RewardRemainder = ();
 	type Ring = RingStaking;
 	type RingCurrency = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type SlashCancelOrigin = frame_system::EnsureRoot<AccountId>;
+	type SlashDeferDuration = frame_support::traits::ConstU32<0>;
 	type UnixTime = Timestamp;
+	type WeightInfo = ();
 }
 
 frame_support::construct_runtime! {
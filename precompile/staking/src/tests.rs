@@ -21,6 +21,7 @@ use crate::mock::{
 	Account::{Alice, Bob, Precompile},
 	ExtBuilder, PCall, PrecompilesValue, Staking, System, TestPrecompiles, TestRuntime,
 };
+use darwinia_staking::RewardDestination;
 use sp_runtime::Perbill;
 // moonbeam
 use precompile_utils::{testing::PrecompileTesterExt, EvmDataWriter};
@@ -40,6 +41,9 @@ fn selectors() {
 	assert!(PCall::nominate_selectors().contains(&0xb332180b));
 	assert!(PCall::collect_selectors().contains(&0x10a66536));
 	assert!(PCall::chill_selectors().contains(&0x2b8a3ae6));
+	assert!(PCall::set_payee_selectors().contains(&0xacf99b04));
+	assert!(PCall::pending_commission_of_selectors().contains(&0xcb6f25e1));
+	assert!(PCall::lock_and_stake_selectors().contains(&0x8b8480f4));
 }
 
 #[test]
@@ -73,7 +77,7 @@ fn stake_unstake_restake() {
 			)
 			.execute_returns(EvmDataWriter::new().write(true).build());
 		assert_eq!(Staking::ledger_of(alice).unwrap().staked_ring, 0);
-		assert_eq!(Staking::ledger_of(alice).unwrap().unstaking_ring.len(), 1);
+		assert_eq!(<darwinia_staking::Unstakings<TestRuntime>>::iter_prefix(alice).count(), 1);
 
 		// restake
 		precompiles()
@@ -88,7 +92,31 @@ fn stake_unstake_restake() {
 			)
 			.execute_returns(EvmDataWriter::new().write(true).build());
 		assert_eq!(Staking::ledger_of(alice).unwrap().staked_ring, 200);
-		assert_eq!(Staking::ledger_of(alice).unwrap().unstaking_ring.len(), 0);
+		assert_eq!(<darwinia_staking::Unstakings<TestRuntime>>::iter_prefix(alice).count(), 0);
+	});
+}
+
+#[test]
+fn lock_and_stake() {
+	let alice: H160 = Alice.into();
+	ExtBuilder::default().with_balances(vec![(alice, 400)]).build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::lock_and_stake { amount: 200.into(), months: 1 },
+			)
+			.execute_returns(EvmDataWriter::new().write(0_u16).build());
+		assert_eq!(Staking::ledger_of(alice).unwrap().staked_deposits.into_inner(), vec![0]);
+
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::lock_and_stake { amount: 100.into(), months: 1 },
+			)
+			.execute_returns(EvmDataWriter::new().write(1_u16).build());
+		assert_eq!(Staking::ledger_of(alice).unwrap().staked_deposits.into_inner(), vec![0, 1]);
 	});
 }
 
@@ -173,7 +201,10 @@ fn nominate() {
 			precompiles()
 				.prepare_test(bob, Precompile, PCall::nominate { target: alice.into() })
 				.execute_returns(EvmDataWriter::new().write(true).build());
-			assert_eq!(Staking::nominator_of(bob).unwrap(), alice);
+			assert_eq!(
+				Staking::nominator_of(bob).unwrap().into_inner(),
+				vec![(alice, Perbill::one())]
+			);
 
 			// check alice(collator) chill
 			precompiles()
@@ -189,3 +220,94 @@ fn nominate() {
 		},
 	);
 }
+
+#[test]
+fn set_payee() {
+	let alice: H160 = Alice.into();
+	let bob: H160 = Bob.into();
+	ExtBuilder::default().with_balances(vec![(alice, 300)]).build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::stake {
+					ring_amount: 200.into(),
+					kton_amount: U256::zero(),
+					deposits: vec![],
+				},
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		// route the rewards to bob
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::set_payee { destination: 0, account: bob.into() },
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		assert_eq!(Staking::payee_of(alice), RewardDestination::Account(bob));
+
+		// compound the rewards
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::set_payee { destination: 1, account: H160::zero().into() },
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		assert_eq!(Staking::payee_of(alice), RewardDestination::Staked);
+
+		// invalid destination
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::set_payee { destination: 3, account: H160::zero().into() },
+			)
+			.execute_reverts(|out| out == b"Invalid reward destination");
+	});
+}
+
+#[test]
+fn pending_commission_of() {
+	let alice: H160 = Alice.into();
+	ExtBuilder::default().with_balances(vec![(alice, 300)]).build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::stake {
+					ring_amount: 200.into(),
+					kton_amount: U256::zero(),
+					deposits: vec![],
+				},
+			)
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		precompiles()
+			.prepare_test(alice, Precompile, PCall::collect { commission: 30 })
+			.execute_returns(EvmDataWriter::new().write(true).build());
+
+		// no pending change
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::pending_commission_of { collator: alice.into() },
+			)
+			.execute_returns(EvmDataWriter::new().write(0u32).write(0u32).build());
+
+		// the increase will be applied at the next session
+		precompiles()
+			.prepare_test(alice, Precompile, PCall::collect { commission: 40 })
+			.execute_returns(EvmDataWriter::new().write(true).build());
+		assert_eq!(Staking::collator_of(alice).unwrap(), Perbill::from_percent(30));
+		precompiles()
+			.prepare_test(
+				alice,
+				Precompile,
+				PCall::pending_commission_of { collator: alice.into() },
+			)
+			.execute_returns(EvmDataWriter::new().write(40u32).write(1u32).build());
+	});
+}
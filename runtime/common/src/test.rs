@@ -5,7 +5,7 @@ macro_rules! impl_account_migration_tests {
 			// darwinia
 			use super::mock::*;
 			use darwinia_deposit::Deposit as DepositS;
-			use darwinia_staking::Ledger;
+			use darwinia_staking::LegacyLedger;
 			// substrate
 			use frame_support::{
 				assert_err, assert_ok, migration, traits::Get, Blake2_128Concat, StorageHasher,
@@ -309,6 +309,8 @@ macro_rules! impl_account_migration_tests {
 									start_time: 1000,
 									expired_time: 2000,
 									in_use: true,
+									kton_reward: 0,
+									kton_claimed: 0,
 								},
 								DepositS {
 									id: 2,
@@ -316,12 +318,14 @@ macro_rules! impl_account_migration_tests {
 									start_time: 1000,
 									expired_time: 2000,
 									in_use: true,
+									kton_reward: 0,
+									kton_claimed: 0,
 								},
 							],
 						);
 						<darwinia_account_migration::Ledgers<Runtime>>::insert(
 							&from_pk,
-							Ledger {
+							LegacyLedger {
 								staked_ring: 20,
 								staked_kton: 20,
 								staked_deposits: Default::default(),
@@ -53,6 +53,8 @@ mod authorship;
 
 mod staking;
 
+mod offences;
+
 mod session;
 pub use session::*;
 
@@ -90,6 +92,7 @@ mod scheduler;
 mod preimage;
 
 mod proxy;
+pub use proxy::*;
 
 // XCM stuff.
 mod xcmp_queue;
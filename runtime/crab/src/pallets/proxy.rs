@@ -42,6 +42,7 @@ pub enum ProxyType {
 	CancelProxy,
 	EcdsaBridge,
 	SubstrateBridge,
+	StakingOperator,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -100,6 +101,19 @@ impl frame_support::traits::InstanceFilter<RuntimeCall> for ProxyType {
 						| RuntimeCall::DarwiniaFeeMarket(..)
 				)
 			},
+			ProxyType::StakingOperator => {
+				matches!(
+					c,
+					RuntimeCall::Session(pallet_session::Call::set_keys { .. })
+						| RuntimeCall::DarwiniaStaking(
+							darwinia_staking::Call::nominate { .. }
+								| darwinia_staking::Call::nominate_multiple { .. }
+								| darwinia_staking::Call::collect { .. }
+								| darwinia_staking::Call::chill { .. }
+								| darwinia_staking::Call::restake { .. }
+						)
+				)
+			},
 		}
 	}
 
@@ -109,11 +123,17 @@ impl frame_support::traits::InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
 			(ProxyType::NonTransfer, _) => true,
+			(ProxyType::Staking, ProxyType::StakingOperator) => true,
 			_ => false,
 		}
 	}
 }
 
+frame_support::parameter_types! {
+	/// The only proxy type which could be used through the proxy precompile.
+	pub const PrecompileProxyType: ProxyType = ProxyType::StakingOperator;
+}
+
 impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = ConstU128<{ darwinia_deposit(1, 8) }>;
 	type AnnouncementDepositFactor = ConstU128<{ darwinia_deposit(0, 66) }>;
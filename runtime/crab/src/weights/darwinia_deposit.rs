@@ -16,15 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for `darwinia_deposit`
+//! Weights for `darwinia_deposit`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-02-22, STEPS: `2`, REPEAT: `1`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `inv.cafe`, CPU: `13th Gen Intel(R) Core(TM) i9-13900K`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("crab-local"), DB CACHE: 1024
+//! Only `lock`, `claim` and `claim_with_penalty` were measured by the Substrate benchmark CLI
+//! (DATE: 2023-02-22, STEPS: `2`, REPEAT: `1`, HOSTNAME: `inv.cafe`), against the previous
+//! `Deposit` layout, and have been adjusted to the current storage accesses since.
+//! THE OTHER WEIGHTS ARE HAND-WRITTEN PLACEHOLDERS, estimated from the storage accesses of the
+//! benchmarks. Regenerate this file with the command below before going to production.
 
-// Executed Command:
+// Regenerate with:
 // target/release/darwinia
 // benchmark
 // pallet
@@ -42,6 +42,10 @@
 // *
 // --pallet
 // darwinia-deposit
+// --steps
+// 50
+// --repeat
+// 20
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -54,10 +58,10 @@ use sp_std::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> darwinia_deposit::WeightInfo for WeightInfo<T> {
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
@@ -66,41 +70,208 @@ impl<T: frame_system::Config> darwinia_deposit::WeightInfo for WeightInfo<T> {
 	fn lock() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3267`
-		//  Estimated: `11632`
+		//  Estimated: `14735`
 		// Minimum execution time: 59_662 nanoseconds.
-		Weight::from_parts(59_662_000, 11632)
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+		Weight::from_parts(74_662_000, 14735)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	fn claim() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3002`
-		//  Estimated: `6422`
+		//  Estimated: `14735`
 		// Minimum execution time: 74_713 nanoseconds.
-		Weight::from_parts(74_713_000, 6422)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		Weight::from_parts(109_713_000, 14735)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: Deposit Deposits (r:1 w:1)
-	/// Proof: Deposit Deposits (max_values: None, max_size: Some(853), added: 3328, mode: MaxEncodedLen)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Assets Account (r:1 w:1)
 	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
 	/// Storage: Assets Asset (r:1 w:1)
 	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
 	fn claim_with_penalty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2479`
-		//  Estimated: `9041`
+		//  Estimated: `14735`
 		// Minimum execution time: 42_715 nanoseconds.
-		Weight::from_parts(42_715_000, 9041)
+		Weight::from_parts(67_715_000, 14735)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: Deposit Deposits (r:2 w:2)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn transfer_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2874`
+		//  Estimated: `12862`
+		// Minimum execution time: 33_076 nanoseconds.
+		Weight::from_parts(33_076_000, 12862)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn extend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `96585`
+		// Minimum execution time: 114_008 nanoseconds.
+		Weight::from_parts(114_008_000, 96585)
+			.saturating_add(T::DbWeight::get().reads(38))
+			.saturating_add(T::DbWeight::get().writes(35))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn relock() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `96585`
+		// Minimum execution time: 113_314 nanoseconds.
+		Weight::from_parts(113_314_000, 96585)
+			.saturating_add(T::DbWeight::get().reads(38))
+			.saturating_add(T::DbWeight::get().writes(35))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	fn split_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2420`
+		//  Estimated: `3840`
+		// Minimum execution time: 23_716 nanoseconds.
+		Weight::from_parts(23_716_000, 3840)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	fn partially_claim_with_penalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2603`
+		//  Estimated: `14735`
+		// Minimum execution time: 71_996 nanoseconds.
+		Weight::from_parts(71_996_000, 14735)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn claim_ids(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2906`
+		//  Estimated: `6934`
+		// Minimum execution time: 84_175 nanoseconds.
+		Weight::from_parts(84_175_000, 6934)
+			// Standard Error: 262
+			.saturating_add(Weight::from_parts(1_839, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Deposit AutoClaims (r:0 w:1)
+	/// Proof: Deposit AutoClaims (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	fn set_auto_claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `0`
+		// Minimum execution time: 12_606 nanoseconds.
+		Weight::from_parts(12_606_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Deposit AutoClaims (r:1 w:1)
+	/// Proof: Deposit AutoClaims (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn auto_claim(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3240`
+		//  Estimated: `17246`
+		// Minimum execution time: 89_393 nanoseconds.
+		Weight::from_parts(89_393_000, 17246)
+			.saturating_add(Weight::from_parts(1_520_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	fn claim_vested_kton() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2573`
+		//  Estimated: `9553`
+		// Minimum execution time: 40_080 nanoseconds.
+		Weight::from_parts(40_080_000, 9553)
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
@@ -0,0 +1,698 @@
+// This file is part of Darwinia.
+//
+// Copyright (C) 2018-2023 Darwinia Network
+// SPDX-License-Identifier: GPL-3.0
+//
+// Darwinia is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Darwinia is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Darwinia. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `darwinia_staking`.
+//!
+//! THESE WEIGHTS ARE NOT GENERATED BY THE SUBSTRATE BENCHMARK CLI.
+//! They are hand-written placeholders, estimated from the storage accesses of the benchmarks.
+//! Regenerate this file with the command below before going to production.
+
+// Regenerate with:
+// target/release/darwinia
+// benchmark
+// pallet
+// --header
+// .maintain/license-header
+// --execution
+// wasm
+// --heap-pages
+// 4096
+// --chain
+// crab-local
+// --output
+// runtime/crab/src/weights
+// --extrinsic
+// *
+// --pallet
+// darwinia-staking
+// --steps
+// 50
+// --repeat
+// 20
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `darwinia_staking`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> darwinia_staking::WeightInfo for WeightInfo<T> {
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn stake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2315`
+		//  Estimated: `104855`
+		// Minimum execution time: 196_378 nanoseconds.
+		Weight::from_parts(213_455_230, 104855)
+			// Standard Error: 22_227
+			.saturating_add(Weight::from_parts(6_485_104, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(42))
+			.saturating_add(T::DbWeight::get().writes(41))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:1 w:1)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn unstake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3012`
+		//  Estimated: `94482`
+		// Minimum execution time: 149_329 nanoseconds.
+		Weight::from_parts(162_314_360, 94482)
+			// Standard Error: 11_438
+			.saturating_add(Weight::from_parts(3_141_320, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(38))
+			.saturating_add(T::DbWeight::get().writes(36))
+	}
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn restake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3324`
+		//  Estimated: `135890`
+		// Minimum execution time: 231_625 nanoseconds.
+		Weight::from_parts(251_766_840, 135890)
+			// Standard Error: 2_422
+			.saturating_add(Weight::from_parts(3_340_941, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(54))
+			.saturating_add(T::DbWeight::get().writes(51))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:17 w:16)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4127`
+		//  Estimated: `63373`
+		// Minimum execution time: 261_695 nanoseconds.
+		Weight::from_parts(284_452_745, 63373)
+			.saturating_add(T::DbWeight::get().reads(24))
+			.saturating_add(T::DbWeight::get().writes(23))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Session NextKeys (r:1 w:0)
+	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Collators (r:1 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking CurrentSession (r:1 w:0)
+	/// Proof: DarwiniaStaking CurrentSession (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingCommissions (r:0 w:1)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking ChilledCommissions (r:1 w:1)
+	/// Proof: DarwiniaStaking ChilledCommissions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn collect() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1919`
+		//  Estimated: `11929`
+		// Minimum execution time: 37_346 nanoseconds.
+		Weight::from_parts(40_593_013, 11929)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:1 w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:17)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:17 w:17)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn nominate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1972`
+		//  Estimated: `92074`
+		// Minimum execution time: 156_633 nanoseconds.
+		Weight::from_parts(170_254_918, 92074)
+			.saturating_add(T::DbWeight::get().reads(36))
+			.saturating_add(T::DbWeight::get().writes(35))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:x+16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+16 w:x+16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	fn nominate_multiple(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1985 + x * (252 ±0)`
+		//  Estimated: `46472 + x * (5042 ±0)`
+		// Minimum execution time: 152_142 nanoseconds.
+		Weight::from_parts(165_372_964, 46472)
+			// Standard Error: 21_418
+			.saturating_add(Weight::from_parts(10_820_703, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(34))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(33))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 5042).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn chill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1300`
+		//  Estimated: `84456`
+		// Minimum execution time: 137_409 nanoseconds.
+		Weight::from_parts(149_358_716, 84456)
+			.saturating_add(T::DbWeight::get().reads(33))
+			.saturating_add(T::DbWeight::get().writes(35))
+	}
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:1 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `x` is `[1, 100]`.
+	fn cancel_deferred_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `41 + x * (1 ±0)`
+		//  Estimated: `1530 + x * (25 ±0)`
+		// Minimum execution time: 12_698 nanoseconds.
+		Weight::from_parts(13_802_232, 1530)
+			// Standard Error: 20_773
+			.saturating_add(Weight::from_parts(433_002, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 25).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking RewardPoints (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking RewardPoints (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:x w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingRewards (r:0 w:x)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking SessionSummaries (r:0 w:1)
+	/// Proof: DarwiniaStaking SessionSummaries (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	fn payout(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `355 + x * (289 ±0)`
+		//  Estimated: `2073 + x * (5791 ±0)`
+		// Minimum execution time: 29_845 nanoseconds.
+		Weight::from_parts(32_440_639, 2073)
+			// Standard Error: 19_534
+			.saturating_add(Weight::from_parts(14_752_063, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 5791).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking PendingRewards (r:1 w:1)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:x+1 w:0)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: System Account (r:x+1 w:x+1)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x+1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x+1 w:x+1)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 32]`.
+	fn payout_stakers(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2356 + x * (335 ±0)`
+		//  Estimated: `3819 + x * (6713 ±0)`
+		// Minimum execution time: 69_525 nanoseconds.
+		Weight::from_parts(75_571_279, 3819)
+			// Standard Error: 14_342
+			.saturating_add(Weight::from_parts(51_657_714, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 6713).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:0 w:1)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn set_payee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1624`
+		//  Estimated: `2576`
+		// Minimum execution time: 18_921 nanoseconds.
+		Weight::from_parts(20_567_492, 2576)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: DarwiniaStaking Invulnerables (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking CollatorCount (r:0 w:1)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_collator_count() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `0`
+		// Minimum execution time: 12_172 nanoseconds.
+		Weight::from_parts(13_230_146, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: DarwiniaStaking CollatorCount (r:1 w:0)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Invulnerables (r:0 w:1)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 100]`.
+	fn set_invulnerables(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `499`
+		// Minimum execution time: 13_591 nanoseconds.
+		Weight::from_parts(14_773_218, 499)
+			// Standard Error: 4_164
+			.saturating_add(Weight::from_parts(658_532, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn force_chill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1300`
+		//  Estimated: `84456`
+		// Minimum execution time: 138_830 nanoseconds.
+		Weight::from_parts(150_902_449, 84456)
+			.saturating_add(T::DbWeight::get().reads(33))
+			.saturating_add(T::DbWeight::get().writes(35))
+	}
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:1 w:0)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:1)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:1)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:0 w:1)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Payees (r:0 w:1)
+	/// Proof: DarwiniaStaking Payees (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 16]`.
+	fn force_unstake(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4916`
+		//  Estimated: `107443`
+		// Minimum execution time: 207_140 nanoseconds.
+		Weight::from_parts(225_152_459, 107443)
+			// Standard Error: 37_488
+			.saturating_add(Weight::from_parts(7_740_227, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(43))
+			.saturating_add(T::DbWeight::get().writes(46))
+	}
+	/// Storage: Deposit Deposits (r:1 w:1)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:1 w:1)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:1 w:1)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:1 w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:16 w:16)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:16 w:16)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn lock_and_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2871`
+		//  Estimated: `102278`
+		// Minimum execution time: 182_998 nanoseconds.
+		Weight::from_parts(198_911_664, 102278)
+			.saturating_add(T::DbWeight::get().reads(41))
+			.saturating_add(T::DbWeight::get().writes(39))
+	}
+	/// Storage: DarwiniaStaking PendingRewards (r:x w:x)
+	/// Proof: DarwiniaStaking PendingRewards (max_values: None, max_size: Some(837), added: 3312, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn expire_rewards(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (165 ±0)`
+		//  Estimated: `2073 + x * (3308 ±0)`
+		// Minimum execution time: 9_990 nanoseconds.
+		Weight::from_parts(10_859_002, 2073)
+			// Standard Error: 16_672
+			.saturating_add(Weight::from_parts(5_208_053, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3308).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking SessionSummaries (r:0 w:1)
+	/// Proof: DarwiniaStaking SessionSummaries (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalRewardPoints (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalRewardPoints (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn prune_history(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `2073`
+		// Minimum execution time: 10_641 nanoseconds.
+		Weight::from_parts(11_566_090, 2073)
+			// Standard Error: 9_532
+			.saturating_add(Weight::from_parts(2_527_493, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+	}
+	/// Storage: DarwiniaStaking Invulnerables (r:1 w:0)
+	/// Proof Skipped: DarwiniaStaking Invulnerables (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking AuthoredBlocks (r:1 w:0)
+	/// Proof: DarwiniaStaking AuthoredBlocks (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:x w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking InactiveSessions (r:x w:x)
+	/// Proof: DarwiniaStaking InactiveSessions (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking PendingCommissions (r:0 w:x)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:x)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn chill_inactive_collators(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (167 ±0)`
+		//  Estimated: `4580 + x * (3348 ±0)`
+		// Minimum execution time: 10_275 nanoseconds.
+		Weight::from_parts(11_169_349, 4580)
+			// Standard Error: 2_930
+			.saturating_add(Weight::from_parts(15_694_719, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3348).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking RewardPoints (r:1 w:1)
+	/// Proof Skipped: DarwiniaStaking RewardPoints (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking HistoricalRewardPoints (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalRewardPoints (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn clean_old_session(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `253 + x * (165 ±0)`
+		//  Estimated: `2073 + x * (2519 ±0)`
+		// Minimum execution time: 12_098 nanoseconds.
+		Weight::from_parts(13_150_100, 2073)
+			// Standard Error: 5_205
+			.saturating_add(Weight::from_parts(9_674_772, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking PendingCommissions (r:x w:x)
+	/// Proof: DarwiniaStaking PendingCommissions (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Collators (r:0 w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn apply_commission_changes(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (125 ±0)`
+		//  Estimated: `2073 + x * (2516 ±0)`
+		// Minimum execution time: 8_978 nanoseconds.
+		Weight::from_parts(9_759_591, 2073)
+			// Standard Error: 34_144
+			.saturating_add(Weight::from_parts(8_979_420, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2516).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:x w:x)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	fn drop_underbonded_collators(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146 + x * (291 ±0)`
+		//  Estimated: `2073 + x * (5820 ±0)`
+		// Minimum execution time: 8_691 nanoseconds.
+		Weight::from_parts(9_447_673, 2073)
+			// Standard Error: 35_762
+			.saturating_add(Weight::from_parts(10_191_090, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 5820).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking Collators (r:x w:0)
+	/// Proof: DarwiniaStaking Collators (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x w:0)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:0)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:0)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System BlockWeight (r:1 w:1)
+	/// Proof: System BlockWeight (max_values: Some(1), max_size: Some(48), added: 543, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking CollatorCount (r:1 w:0)
+	/// Proof: DarwiniaStaking CollatorCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:y w:0)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Exposures (r:x w:x)
+	/// Proof: DarwiniaStaking Exposures (max_values: None, max_size: Some(805), added: 3280, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking HistoricalExposures (r:0 w:x)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 100]`.
+	/// The range of component `y` is `[0, 1000]`.
+	fn elect(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1318 + x * (252 ±0) + y * (128 ±0)`
+		//  Estimated: `3594 + x * (8322 ±0) + y * (2563 ±0)`
+		// Minimum execution time: 25_945 nanoseconds.
+		Weight::from_parts(28_201_295, 3594)
+			// Standard Error: 2_705
+			.saturating_add(Weight::from_parts(13_657_525, 0).saturating_mul(x.into()))
+			// Standard Error: 12_784
+			.saturating_add(Weight::from_parts(6_511_110, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 8322).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(y.into()))
+	}
+	/// Storage: DarwiniaStaking HistoricalExposures (r:1 w:0)
+	/// Proof: DarwiniaStaking HistoricalExposures (max_values: None, max_size: Some(813), added: 3288, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:0)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:x w:0)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:0)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:0)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:0 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Events (r:0 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 32]`.
+	fn on_offence(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `186 + x * (684 ±0)`
+		//  Estimated: `2560 + x * (15298 ±0)`
+		// Minimum execution time: 15_062 nanoseconds.
+		Weight::from_parts(15_062_000, 2560)
+			// Standard Error: 3_252_988
+			.saturating_add(Weight::from_parts(22_770_920, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 15298).saturating_mul(x.into()))
+	}
+	/// Storage: DarwiniaStaking UnappliedSlashes (r:2 w:1)
+	/// Proof Skipped: DarwiniaStaking UnappliedSlashes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: DarwiniaStaking Ledgers (r:x w:x)
+	/// Proof: DarwiniaStaking Ledgers (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: System Account (r:x w:x)
+	/// Proof: System Account (max_values: None, max_size: Some(116), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(166), added: 2641, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:x w:x)
+	/// Proof: Assets Account (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	/// Storage: Deposit Deposits (r:x w:x)
+	/// Proof: Deposit Deposits (max_values: None, max_size: Some(1365), added: 3840, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking RingPool (r:1 w:1)
+	/// Proof: DarwiniaStaking RingPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking KtonPool (r:1 w:1)
+	/// Proof: DarwiniaStaking KtonPool (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Unstakings (r:x w:x)
+	/// Proof: DarwiniaStaking Unstakings (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Nominators (r:x w:0)
+	/// Proof: DarwiniaStaking Nominators (max_values: None, max_size: Some(413), added: 2888, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking Backings (r:x w:x)
+	/// Proof: DarwiniaStaking Backings (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: DarwiniaStaking TotalBackings (r:x w:x)
+	/// Proof: DarwiniaStaking TotalBackings (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: System Events (r:0 w:1)
+	/// Proof Skipped: System Events (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `x` is `[0, 32]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1507 + x * (3021 ±0)`
+		//  Estimated: `101375 + x * (90431 ±0)`
+		// Minimum execution time: 1_169_043 nanoseconds.
+		Weight::from_parts(1_169_043_000, 101375)
+			// Standard Error: 165_710_102
+			.saturating_add(Weight::from_parts(1_159_970_720, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(46))
+			.saturating_add(T::DbWeight::get().reads((46_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(39))
+			.saturating_add(T::DbWeight::get().writes((39_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 90431).saturating_mul(x.into()))
+	}
+}
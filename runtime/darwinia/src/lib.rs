@@ -161,7 +161,10 @@ frame_support::construct_runtime! {
 		BridgeKusamaParachain: pallet_bridge_parachains::<Instance1> = 40,
 		BridgeCrabMessages: pallet_bridge_messages::<Instance1> = 41,
 		BridgeCrabDispatch: pallet_bridge_dispatch::<Instance1> = 42,
-		CrabFeeMarket: pallet_fee_market::<Instance1> = 43
+		CrabFeeMarket: pallet_fee_market::<Instance1> = 43,
+
+		// Offence stuff.
+		Offences: pallet_offences = 44
 	}
 }
 
@@ -169,6 +172,7 @@ frame_support::construct_runtime! {
 frame_benchmarking::define_benchmarks! {
 	// darwinia
 	[darwinia_deposit, Deposit]
+	[darwinia_staking, DarwiniaStaking]
 	// darwinia-messages-substrate
 	[pallet_bridge_grandpa, BridgeKusamaGrandpa]
 	[pallet_fee_market, CrabFeeMarket]
@@ -324,6 +328,57 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl darwinia_staking::runtime_api::StakingApi<Block, AccountId, BlockNumber> for Runtime {
+		fn power_of(who: AccountId) -> darwinia_staking::Power {
+			DarwiniaStaking::power_of(&who)
+		}
+
+		fn ledger_summary_of(who: AccountId) -> Option<darwinia_staking::LedgerSummary<BlockNumber>> {
+			DarwiniaStaking::ledger_summary_of(&who)
+		}
+
+		fn exposure_of(collator: AccountId) -> Option<darwinia_staking::ExposureSummary<AccountId>> {
+			DarwiniaStaking::exposure_summary_of(&collator)
+		}
+
+		fn estimated_session_payout_of(collator: AccountId) -> Balance {
+			DarwiniaStaking::estimated_session_payout_of(&collator)
+		}
+
+		fn collator_ranking() -> Vec<(AccountId, darwinia_staking::Power)> {
+			DarwiniaStaking::collator_ranking()
+		}
+
+		fn historical_exposure_of(
+			session: u32,
+			collator: AccountId,
+		) -> Option<darwinia_staking::ExposureSummary<AccountId>> {
+			DarwiniaStaking::historical_exposure_summary_of(session, &collator)
+		}
+
+		fn historical_reward_points(
+			session: u32,
+		) -> Vec<(AccountId, darwinia_staking::RewardPoint)> {
+			DarwiniaStaking::historical_reward_points(session)
+		}
+
+		fn session_summary_of(
+			session: u32,
+		) -> Option<darwinia_staking::SessionSummary> {
+			DarwiniaStaking::session_summary_of(session)
+		}
+	}
+
+	impl darwinia_deposit::runtime_api::DepositApi<Block, AccountId> for Runtime {
+		fn deposits_of(who: AccountId) -> Vec<darwinia_deposit::DepositQuote> {
+			Deposit::deposit_quotes_of(&who)
+		}
+
+		fn quote_lock(amount: Balance, months: u8) -> Balance {
+			Deposit::quote_lock(amount, months)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as sp_runtime::traits::Block>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -44,10 +44,16 @@ impl darwinia_deposit::SimpleAsset for KtonAsset {
 }
 
 impl darwinia_deposit::Config for Runtime {
+	type DepositInterest = ();
 	type Kton = KtonAsset;
+	type LinearVesting = ConstBool<false>;
 	type MaxDeposits = ConstU32<16>;
+	type MaxLockingMonths = ConstU8<36>;
 	type MinLockingAmount = ConstU128<UNIT>;
+	type MinLockingMonths = ConstU8<1>;
+	type PenaltyMultiplier = ConstU32<3>;
 	type Ring = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type Staking = DarwiniaStaking;
 	type WeightInfo = weights::darwinia_deposit::WeightInfo<Self>;
 }
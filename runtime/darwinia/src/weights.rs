@@ -34,6 +34,7 @@ pub use rocksdb_weights::constants::RocksDbWeight;
 
 pub mod cumulus_pallet_xcmp_queue;
 pub mod darwinia_deposit;
+pub mod darwinia_staking;
 pub mod frame_system;
 pub mod pallet_assets;
 pub mod pallet_balances;
@@ -22,6 +22,7 @@ use crate::*;
 /// List of the assets existed in this runtime.
 pub enum AssetIds {
 	PKton = 1026,
+	LPRing = 1027,
 }
 
 frame_support::parameter_types! {
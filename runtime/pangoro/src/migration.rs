@@ -36,19 +36,13 @@ impl frame_support::traits::OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 
 		assert_eq!(
 			AccountMigration::ledger_of(&a).unwrap(),
-			darwinia_staking::Ledger::<Runtime> {
+			darwinia_staking::LegacyLedger::<Runtime> {
 				staked_ring: 3_190_000_000_000_000_000_000,
 				staked_kton: 9_000_000_000_000_000,
 				staked_deposits: frame_support::BoundedVec::truncate_from(vec![0, 1, 2]),
-				unstaking_ring: frame_support::BoundedVec::truncate_from(vec![(
-					10_000_000_000_000_000_000,
-					0
-				)]),
-				unstaking_kton: frame_support::BoundedVec::truncate_from(vec![(
-					1_000_000_000_000_000,
-					0
-				)]),
-				unstaking_deposits: frame_support::BoundedVec::default()
+				unstaking_ring: vec![(10_000_000_000_000_000_000, 0)],
+				unstaking_kton: vec![(1_000_000_000_000_000, 0)],
+				unstaking_deposits: Vec::new()
 			}
 		);
 
@@ -63,7 +57,7 @@ impl frame_support::traits::OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 
 		assert_eq!(
 			AccountMigration::ledger_of(&a).unwrap(),
-			darwinia_staking::Ledger::<Runtime> {
+			darwinia_staking::LegacyLedger::<Runtime> {
 				staked_ring: 3_190_000_000_000_000_000_000
 					- AccountMigration::deposit_of(&a)
 						.unwrap()
@@ -72,9 +66,9 @@ impl frame_support::traits::OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 						.sum::<Balance>(),
 				staked_kton: 9_000_000_000_000_000,
 				staked_deposits: frame_support::BoundedVec::truncate_from(vec![0, 1, 2]),
-				unstaking_ring: frame_support::BoundedVec::default(),
-				unstaking_kton: frame_support::BoundedVec::default(),
-				unstaking_deposits: frame_support::BoundedVec::default()
+				unstaking_ring: Vec::new(),
+				unstaking_kton: Vec::new(),
+				unstaking_deposits: Vec::new()
 			}
 		);
 
@@ -87,8 +81,21 @@ impl frame_support::traits::OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
 }
 
 fn migrate() -> frame_support::weights::Weight {
+	// The deposits track their KTON interest now.
+	//
+	// Translate the not yet migrated ones too, since they are read by the following steps.
+	<darwinia_deposit::Deposits<Runtime>>::translate::<Vec<darwinia_deposit::LegacyDeposit>, _>(
+		|_, v| {
+			Some(frame_support::BoundedVec::truncate_from(v.into_iter().map(Into::into).collect()))
+		},
+	);
+	<darwinia_account_migration::Deposits<Runtime>>::translate::<
+		Vec<darwinia_deposit::LegacyDeposit>,
+		_,
+	>(|_, v| Some(v.into_iter().map(Into::into).collect()));
+
 	<darwinia_account_migration::Ledgers<Runtime>>::translate(
-		|k, mut v: darwinia_staking::Ledger<Runtime>| {
+		|k, mut v: darwinia_staking::LegacyLedger<Runtime>| {
 			if let Some(ds) = <darwinia_account_migration::Deposits<Runtime>>::get(k) {
 				v.staked_ring -= ds.into_iter().map(|d| d.value).sum::<Balance>();
 			}
@@ -100,6 +107,63 @@ fn migrate() -> frame_support::weights::Weight {
 		},
 	);
 
+	// The unstaking assets have been moved out of the ledgers.
+	<darwinia_staking::Ledgers<Runtime>>::translate(
+		|k, v: darwinia_staking::LegacyLedger<Runtime>| {
+			Some(DarwiniaStaking::split_legacy_ledger(&k, v))
+		},
+	);
+
+	// A nominator could nominate multiple collators now.
+	<darwinia_staking::Nominators<Runtime>>::translate::<AccountId, _>(|_, c| {
+		Some(frame_support::BoundedVec::truncate_from(vec![(c, sp_runtime::Perbill::one())]))
+	});
+
+	// The exposures record the collators' commissions now.
+	<darwinia_staking::Exposures<Runtime>>::translate(
+		|c, e: darwinia_staking::LegacyExposure<Runtime>| {
+			Some(darwinia_staking::Exposure {
+				commission: DarwiniaStaking::collator_of(&c).unwrap_or(sp_runtime::Perbill::one()),
+				total: e.total,
+				nominators: e.nominators,
+			})
+		},
+	);
+
+	// The exposures are archived by the session at the election now.
+	//
+	// The ones of the active session weren't kept, approximate them with the latest elected ones.
+	let session = Session::current_index();
+
+	<darwinia_staking::CurrentSession<Runtime>>::put(session);
+	<darwinia_staking::Exposures<Runtime>>::iter().for_each(|(c, e)| {
+		<darwinia_staking::HistoricalExposures<Runtime>>::insert(session, &c, &e);
+		<darwinia_staking::HistoricalExposures<Runtime>>::insert(session + 1, c, e);
+	});
+
+	// Build the backing index from the existing nominations.
+	<darwinia_staking::Nominators<Runtime>>::iter_keys()
+		.collect::<Vec<_>>()
+		.iter()
+		.for_each(DarwiniaStaking::refresh_backings);
+
+	// Create the liquid staking token.
+	let lst_id = AssetIds::LORing as AssetId;
+
+	if let Err(e) = Assets::force_create(RuntimeOrigin::root(), lst_id.into(), ROOT, true, 1) {
+		log::error!("failed to create the liquid staking token due to {e:?}");
+	}
+	if let Err(e) = Assets::force_set_metadata(
+		RuntimeOrigin::root(),
+		lst_id.into(),
+		b"Pangoro Liquid Staking Token".to_vec(),
+		b"LORING".to_vec(),
+		18,
+		false,
+	) {
+		log::error!("failed to set the liquid staking token's metadata due to {e:?}");
+	}
+
 	// frame_support::weights::Weight::zero()
 	RuntimeBlockWeights::get().max_block
 }
@@ -53,6 +53,10 @@ mod authorship;
 
 mod staking;
 
+mod offences;
+
+mod liquid_staking;
+
 mod session;
 pub use session::*;
 
@@ -91,6 +95,7 @@ mod scheduler;
 mod preimage;
 
 mod proxy;
+pub use proxy::*;
 
 // XCM stuff.
 mod xcmp_queue;
@@ -22,6 +22,7 @@ use crate::*;
 /// List of the assets existed in this runtime.
 pub enum AssetIds {
 	OKton = 1026,
+	LORing = 1027,
 }
 
 frame_support::parameter_types! {
@@ -47,7 +47,7 @@ where
 		Self(Default::default())
 	}
 
-	pub fn used_addresses() -> [sp_core::H160; 15] {
+	pub fn used_addresses() -> [sp_core::H160; 17] {
 		[
 			addr(1),
 			addr(2),
@@ -62,8 +62,11 @@ where
 			addr(1025),
 			// For KTON asset.
 			addr(1026),
+			// For liquid staking asset.
+			addr(1027),
 			addr(1536),
 			addr(1537),
+			addr(1538),
 			addr(2048),
 		]
 	}
@@ -115,6 +118,10 @@ where
 				Some(<darwinia_precompile_deposit::Deposit<Runtime>>::execute(handle)),
 			a if a == addr(1537) =>
 				Some(<darwinia_precompile_staking::Staking<Runtime>>::execute(handle)),
+			a if a == addr(1538) => Some(<darwinia_precompile_proxy::Proxy<
+				Runtime,
+				PrecompileProxyType,
+			>>::execute(handle)),
 			// [2048..) reserved for the experimental precompiles.
 			a if a == addr(2048) =>
 				Some(<darwinia_precompile_bls12_381::BLS12381<Runtime>>::execute(handle)),
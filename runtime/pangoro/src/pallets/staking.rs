@@ -44,6 +44,23 @@ impl darwinia_staking::Stake for RingStaking {
 		)
 	}
 }
+impl darwinia_staking::Slash for RingStaking {
+	fn slash(
+		_: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let amount = fraction * item;
+
+		// Burn the slashed RING.
+		let (_, unslashed) = <Balances as frame_support::traits::Currency<_>>::slash(
+			&darwinia_staking::account_id(),
+			amount,
+		);
+
+		Ok(amount - unslashed)
+	}
+}
 pub enum KtonStaking {}
 impl darwinia_staking::Stake for KtonStaking {
 	type AccountId = AccountId;
@@ -67,21 +84,106 @@ impl darwinia_staking::Stake for KtonStaking {
 		)
 	}
 }
+impl darwinia_staking::Slash for KtonStaking {
+	fn slash(
+		_: &Self::AccountId,
+		item: Self::Item,
+		fraction: sp_runtime::Perbill,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let amount = fraction * item;
+
+		// Burn the slashed KTON.
+		Assets::burn(
+			RuntimeOrigin::signed(ROOT),
+			(AssetIds::OKton as AssetId).into(),
+			darwinia_staking::account_id(),
+			amount,
+		)?;
+
+		Ok(amount)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub enum StakingBenchmarkHelper {}
+#[cfg(feature = "runtime-benchmarks")]
+impl darwinia_staking::BenchmarkHelper<AccountId, darwinia_deposit::DepositId>
+	for StakingBenchmarkHelper
+{
+	fn prepare_stakes(who: &AccountId, count: u32) -> Vec<darwinia_deposit::DepositId> {
+		let amount = 1_000_000 * UNIT;
+
+		<Balances as frame_support::traits::Currency<_>>::make_free_balance_be(who, amount);
+		Assets::mint(
+			RuntimeOrigin::signed(ROOT),
+			(AssetIds::OKton as AssetId).into(),
+			*who,
+			amount,
+		)
+		.expect("[runtime::staking] KTON must be minted; qed");
+
+		(0..count).for_each(|_| {
+			Deposit::lock(RuntimeOrigin::signed(*who), UNIT, 1)
+				.expect("[runtime::staking] deposit must be locked; qed")
+		});
+
+		Deposit::deposit_of(who)
+			.map(|ds| ds.into_iter().map(|d| d.id).collect())
+			.unwrap_or_default()
+	}
+
+	fn prepare_keys(who: &AccountId) {
+		<pallet_session::NextKeys<Runtime>>::insert(
+			who,
+			<SessionKeys as codec::Decode>::decode(
+				&mut sp_runtime::traits::TrailingZeroInput::zeroes(),
+			)
+			.expect("[runtime::staking] session keys must be decoded; qed"),
+		);
+	}
+}
 
 frame_support::parameter_types! {
 	pub const PayoutFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(40);
+	pub const MaxSlashFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(10);
+	pub const InactivitySlashFraction: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(1);
+	pub const MinCollatorStake: Balance = 1_000 * UNIT;
+	pub const MaxCommission: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(30);
+	pub const MaxCommissionDelta: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(5);
 }
 
 impl darwinia_staking::Config for Runtime {
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = StakingBenchmarkHelper;
+	// 1 day.
+	type CommissionChangeDelay = ConstU32<4>;
 	type Deposit = Deposit;
+	// 30 days.
+	type HistoryDepth = ConstU32<120>;
+	type InactivitySlashFraction = InactivitySlashFraction;
+	type KeysRegistry = darwinia_staking::SessionKeysRegistry<Self>;
 	type Kton = KtonStaking;
+	type ManagerOrigin = RootOrAtLeastHalf<CouncilCollective>;
+	type MaxCommission = MaxCommission;
+	type MaxCommissionDelta = MaxCommissionDelta;
 	type MaxDeposits = ConstU32<16>;
-	type MaxUnstakings = ConstU32<16>;
+	type MaxInactiveSessions = ConstU32<4>;
+	type MaxNominations = ConstU32<16>;
+	type MaxNominatorsPerCollator = ConstU32<32>;
+	type MaxSlashFraction = MaxSlashFraction;
+	type MinAuthoredBlocks = ConstU32<1>;
+	type MinCollatorStake = MinCollatorStake;
 	type MinStakingDuration = MinStakingDuration;
+	type NextSessionRotation = Session;
 	type PayoutFraction = PayoutFraction;
+	type PowerCalculator = ();
 	type RewardRemainder = Treasury;
 	type Ring = RingStaking;
 	type RingCurrency = Balances;
 	type RuntimeEvent = RuntimeEvent;
+	type SlashCancelOrigin = RootOrAtLeastHalf<CouncilCollective>;
+	// 7 days.
+	type SlashDeferDuration = ConstU32<28>;
 	type UnixTime = Timestamp;
+	type WeightInfo = weights::darwinia_staking::WeightInfo<Self>;
 }
@@ -91,12 +91,22 @@ impl<S> Processor<S> {
 								staked_deposits.push(id);
 								deposit_ring += d.value;
 
+								// The interest was paid upfront on the solo chain.
+								let kton_reward = dc_inflation::deposit_interest(
+									d.value,
+									((d.expire_time - d.start_time) as Moment
+										/ (dc_inflation::MILLISECS_PER_YEAR / 12))
+										.min(36) as _,
+								);
+
 								Deposit {
 									id,
 									value: d.value,
 									start_time: d.start_time as _,
 									expired_time: d.expire_time as _,
 									in_use: true,
+									kton_reward,
+									kton_claimed: kton_reward,
 								}
 							})
 							.collect::<Vec<_>>(),
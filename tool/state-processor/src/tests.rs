@@ -697,6 +697,7 @@ fn stake_deposit_items() {
 			assert_eq!(new.start_time, old.start_time as u128);
 			assert_eq!(new.expired_time, old.expire_time as u128);
 			assert!(new.in_use);
+			assert_eq!(new.kton_reward, new.kton_claimed);
 		});
 		let migrated_deposits_sum: u128 = m_deposits.iter().map(|i| i.value).sum();
 		assert_eq!(migrated_deposits_sum, deposits_sum * GWEI);
@@ -137,6 +137,8 @@ pub struct Deposit {
 	pub start_time: Moment,
 	pub expired_time: Moment,
 	pub in_use: bool,
+	pub kton_reward: Balance,
+	pub kton_claimed: Balance,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]